@@ -1,14 +1,33 @@
 use gitql_cli::printer::OutputFormatKind;
+use llql::check::Severity;
+use llql::ir::module_parser::STDIN_PATH;
+use llql::ir::module_store::LoadOptions;
+use llql::ir::parallel::default_jobs;
+
+/// Output format of the results, printed by GitQL printers or by LLQL
+#[derive(Debug, PartialEq)]
+pub enum OutputFormat {
+    GitQL(OutputFormatKind),
+    Markdown,
+    Html,
+    Sarif,
+}
 
 /// Arguments for LLQL
 #[derive(Debug, PartialEq)]
 pub struct Arguments {
     pub files: Vec<String>,
+    pub include_globs: Vec<String>,
+    pub exclude_globs: Vec<String>,
+    pub compile_commands: Option<String>,
+    pub jobs: usize,
+    pub use_cache: bool,
     pub analysis: bool,
     pub pagination: bool,
     pub page_size: usize,
     pub enable_line_editor: bool,
-    pub output_format: OutputFormatKind,
+    pub output_format: OutputFormat,
+    pub fail_on: Severity,
 }
 
 /// Create a new instance of Arguments with the default settings
@@ -16,11 +35,28 @@ impl Arguments {
     fn new() -> Arguments {
         Arguments {
             files: vec![],
+            include_globs: vec![],
+            exclude_globs: vec![],
+            compile_commands: None,
+            jobs: default_jobs(),
+            use_cache: true,
             analysis: false,
             pagination: false,
             page_size: 10,
             enable_line_editor: false,
-            output_format: OutputFormatKind::Table,
+            output_format: OutputFormat::GitQL(OutputFormatKind::Table),
+            fail_on: Severity::Error,
+        }
+    }
+}
+
+impl Arguments {
+    /// Return the options of loading the modules
+    pub fn load_options(&self) -> LoadOptions {
+        LoadOptions {
+            jobs: self.jobs,
+            use_cache: self.use_cache,
+            stdin_content: None,
         }
     }
 }
@@ -30,6 +66,7 @@ pub enum Command {
     ReplMode(Arguments),
     QueryMode(String, Arguments),
     ScriptMode(String, Arguments),
+    CheckMode(String, Arguments),
     Help,
     Version,
     Error(String),
@@ -48,9 +85,12 @@ pub fn parse_arguments(args: &[String]) -> Command {
 
     let mut optional_query: Option<String> = None;
     let mut optional_script_file: Option<String> = None;
+    let mut optional_rules_file: Option<String> = None;
     let mut arguments = Arguments::new();
 
-    let mut arg_index = 1;
+    // The `check` sub command runs rules files instead of queries
+    let is_check_mode = args.get(1).is_some_and(|arg| arg == "check");
+    let mut arg_index = if is_check_mode { 2 } else { 1 };
     loop {
         if arg_index >= args_len {
             break;
@@ -76,7 +116,7 @@ pub fn parse_arguments(args: &[String]) -> Command {
                     }
 
                     let files = &args[arg_index];
-                    if !files.starts_with('-') {
+                    if !files.starts_with('-') || files == STDIN_PATH {
                         arguments.files.push(files.to_string());
                         arg_index += 1;
                         continue;
@@ -89,6 +129,71 @@ pub fn parse_arguments(args: &[String]) -> Command {
                     return Command::Error("Must provide one or more C/C++ files".to_string());
                 }
             }
+            "--include" | "--exclude" => {
+                arg_index += 1;
+                if arg_index >= args_len {
+                    let message = format!("Argument {arg} must be followed by glob pattern");
+                    return Command::Error(message);
+                }
+
+                let pattern = args[arg_index].to_string();
+                if arg == "--include" {
+                    arguments.include_globs.push(pattern);
+                } else {
+                    arguments.exclude_globs.push(pattern);
+                }
+                arg_index += 1;
+            }
+            "--compile-commands" => {
+                arg_index += 1;
+                if arg_index >= args_len {
+                    let message = format!("Argument {arg} must be followed by the file");
+                    return Command::Error(message);
+                }
+
+                arguments.compile_commands = Some(args[arg_index].to_string());
+                arg_index += 1;
+            }
+            "--jobs" | "-j" => {
+                arg_index += 1;
+                if arg_index >= args_len {
+                    let message = format!("Argument {arg} must be followed by the number of jobs");
+                    return Command::Error(message);
+                }
+
+                match args[arg_index].parse::<usize>() {
+                    Ok(jobs) if jobs > 0 => arguments.jobs = jobs,
+                    _ => return Command::Error("Invalid number of jobs".to_string()),
+                }
+                arg_index += 1;
+            }
+            "--no-cache" => {
+                arguments.use_cache = false;
+                arg_index += 1;
+            }
+            "--rules" | "-r" if is_check_mode => {
+                arg_index += 1;
+                if arg_index >= args_len {
+                    let message = format!("Argument {arg} must be followed by the rules file");
+                    return Command::Error(message);
+                }
+
+                optional_rules_file = Some(args[arg_index].to_string());
+                arg_index += 1;
+            }
+            "--fail-on" if is_check_mode => {
+                arg_index += 1;
+                if arg_index >= args_len {
+                    let message = format!("Argument {arg} must be followed by the severity");
+                    return Command::Error(message);
+                }
+
+                match Severity::parse(&args[arg_index]) {
+                    Some(severity) => arguments.fail_on = severity,
+                    None => return Command::Error("Invalid severity".to_string()),
+                }
+                arg_index += 1;
+            }
             "--query" | "-q" => {
                 arg_index += 1;
                 if arg_index >= args_len {
@@ -145,17 +250,16 @@ pub fn parse_arguments(args: &[String]) -> Command {
                 }
 
                 let output_type = &args[arg_index].to_lowercase();
-                if output_type == "csv" {
-                    arguments.output_format = OutputFormatKind::CSV;
-                } else if output_type == "json" {
-                    arguments.output_format = OutputFormatKind::JSON;
-                } else if output_type == "yaml" {
-                    arguments.output_format = OutputFormatKind::YAML;
-                } else if output_type == "render" || output_type == "table" {
-                    arguments.output_format = OutputFormatKind::Table;
-                } else {
-                    return Command::Error("Invalid output format".to_string());
-                }
+                arguments.output_format = match output_type.as_str() {
+                    "csv" => OutputFormat::GitQL(OutputFormatKind::CSV),
+                    "json" => OutputFormat::GitQL(OutputFormatKind::JSON),
+                    "yaml" => OutputFormat::GitQL(OutputFormatKind::YAML),
+                    "render" | "table" => OutputFormat::GitQL(OutputFormatKind::Table),
+                    "markdown" | "md" => OutputFormat::Markdown,
+                    "html" => OutputFormat::Html,
+                    "sarif" => OutputFormat::Sarif,
+                    _ => return Command::Error("Invalid output format".to_string()),
+                };
 
                 arg_index += 1;
             }
@@ -163,10 +267,17 @@ pub fn parse_arguments(args: &[String]) -> Command {
         }
     }
 
-    if arguments.files.is_empty() {
+    if arguments.files.is_empty() && arguments.compile_commands.is_none() {
         return Command::Error("Must provide one or more LLVM IR or BC files".to_string());
     }
 
+    if is_check_mode {
+        return match optional_rules_file {
+            Some(rules_file) => Command::CheckMode(rules_file, arguments),
+            None => Command::Error("Command check must have --rules file".to_string()),
+        };
+    }
+
     if let Some(script_file) = optional_script_file {
         Command::ScriptMode(script_file, arguments)
     } else if let Some(query) = optional_query {
@@ -180,15 +291,23 @@ pub fn print_help_list() {
     println!("LLQL is a SQL like query language to run on local files");
     println!();
     println!("Usage: LLQL [OPTIONS]");
+    println!("       LLQL check --rules <file> [OPTIONS]");
     println!();
     println!("Options:");
-    println!("-f,  --files <paths>        Path for local files to run query on");
+    println!("-f,  --files <paths>        Path for local files, directories or @response files");
+    println!("     --include <glob>       Include only matching files from directories");
+    println!("     --exclude <glob>       Exclude matching files from directories");
+    println!("     --compile-commands <file> Load Bitcode outputs of compile_commands.json");
     println!("-s,  --script <file>        Script file contains one or more query");
     println!("-q,  --query <GQL Query>    LLQL query to run on selected repositories");
     println!("-p,  --pagination           Enable print result with pagination");
     println!("-ps, --pagesize             Set pagination page size [default: 10]");
-    println!("-o,  --output               Set output format [render, json, csv]");
+    println!("-o,  --output               Set output format [render, json, csv, yaml, markdown, html, sarif]");
+    println!("-j,  --jobs <N>             Number of threads to load and query modules");
+    println!("     --no-cache             Don't read or write the modules index cache");
     println!("-a,  --analysis             Print Query analysis");
+    println!("-r,  --rules <file>         Rules file to run in check mode");
+    println!("     --fail-on <severity>   Fail check on findings of severity [default: error]");
     println!("-h,  --help                 Print LLQL help");
     println!("-v,  --version              Print LLQL Current Version");
 }
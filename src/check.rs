@@ -0,0 +1,396 @@
+use std::fmt;
+use std::fs;
+
+use gitql_core::values::Value;
+
+use crate::inputs::validate_files_paths;
+use crate::ir::module_store::LoadOptions;
+use crate::ir::module_store::ModuleStore;
+use crate::query::LLQLSession;
+
+/// Exit code of `llql check` when the rules or the modules can't be loaded, or a rule failed
+pub const CHECK_ERROR_EXIT_CODE: i32 = 2;
+
+/// Severity of the rule findings ordered from the lowest to the highest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Note,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Parse the severity name, for example `warning`
+    pub fn parse(name: &str) -> Option<Severity> {
+        match name.to_lowercase().as_str() {
+            "note" => Some(Severity::Note),
+            "warning" => Some(Severity::Warning),
+            "error" => Some(Severity::Error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Note => write!(f, "note"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Named query that reports each selected row as a finding
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rule {
+    pub name: String,
+    pub severity: Severity,
+    pub message: String,
+    pub query: String,
+}
+
+/// Row selected by a rule query
+pub struct Finding {
+    pub rule: String,
+    pub severity: Severity,
+    pub message: String,
+    pub column_names: Vec<String>,
+    pub values: Vec<Box<dyn Value>>,
+}
+
+impl Finding {
+    /// Return the value of the column with this name
+    #[allow(clippy::borrowed_box)]
+    pub fn column(&self, name: &str) -> Option<&Box<dyn Value>> {
+        let index = self.column_names.iter().position(|column| column == name)?;
+        self.values.get(index)
+    }
+}
+
+/// Parse rules file, each rule starts with `-- @rule <name>` comment and may have
+/// `-- @severity <note|warning|error>` and `-- @message <text>` comments before its query
+///
+/// ```sql
+/// -- @rule no-alloca-call
+/// -- @severity error
+/// -- @message Call to alloca in {function_name}
+/// SELECT function_name FROM instructions WHERE m_inst(instruction, m_call('alloca'));
+/// ```
+pub fn parse_rules(content: &str) -> Result<Vec<Rule>, String> {
+    let mut rules: Vec<Rule> = vec![];
+
+    for (line_index, line) in content.lines().enumerate() {
+        let line_number = line_index + 1;
+
+        let directive = line
+            .trim()
+            .strip_prefix("--")
+            .map(|comment| comment.trim())
+            .and_then(|comment| comment.strip_prefix('@'));
+
+        let Some(directive) = directive else {
+            if let Some(rule) = rules.last_mut() {
+                rule.query.push_str(line);
+                rule.query.push('\n');
+            } else if !line.trim().is_empty() && !line.trim().starts_with("--") {
+                return Err(format!(
+                    "Line {line_number}: Query must follow `-- @rule` name"
+                ));
+            }
+            continue;
+        };
+
+        let (key, value) = directive.split_once(' ').unwrap_or((directive, ""));
+        let value = value.trim();
+
+        if key == "rule" {
+            if value.is_empty() {
+                return Err(format!("Line {line_number}: Rule must have a name"));
+            }
+
+            if rules.iter().any(|rule| rule.name == value) {
+                return Err(format!(
+                    "Line {line_number}: Rule `{value}` is already defined"
+                ));
+            }
+
+            rules.push(Rule {
+                name: value.to_string(),
+                severity: Severity::Warning,
+                message: value.to_string(),
+                query: String::new(),
+            });
+            continue;
+        }
+
+        let Some(rule) = rules.last_mut() else {
+            return Err(format!(
+                "Line {line_number}: `@{key}` must follow `-- @rule` name"
+            ));
+        };
+
+        match key {
+            "severity" => {
+                rule.severity = Severity::parse(value).ok_or_else(|| {
+                    format!("Line {line_number}: Unknown severity `{value}`, expect note, warning or error")
+                })?;
+            }
+            "message" => rule.message = value.to_string(),
+            _ => {
+                return Err(format!(
+                    "Line {line_number}: Unknown rule directive `@{key}`"
+                ))
+            }
+        }
+    }
+
+    if let Some(rule) = rules.iter().find(|rule| rule.query.trim().is_empty()) {
+        return Err(format!("Rule `{}` has no query", rule.name));
+    }
+
+    Ok(rules)
+}
+
+/// Run the rules queries and return every selected row as a finding
+///
+/// `{column}` in the rule message is replaced by the value of this column in the row
+pub fn check_rules(session: &mut LLQLSession, rules: &[Rule]) -> Result<Vec<Finding>, String> {
+    let mut findings: Vec<Finding> = vec![];
+    for rule in rules {
+        let results = session
+            .query(&rule.query)
+            .map_err(|error| format!("Rule `{}`: {error}", rule.name))?;
+
+        for result in results {
+            for values in result.rows {
+                let message = format_message(&rule.message, &result.column_names, &values);
+                findings.push(Finding {
+                    rule: rule.name.to_string(),
+                    severity: rule.severity,
+                    message,
+                    column_names: result.column_names.clone(),
+                    values,
+                });
+            }
+        }
+    }
+    Ok(findings)
+}
+
+/// Replace each `{column}` in the message by the value of this column in one pass, so
+/// placeholders inside the values are kept as they are
+fn format_message(template: &str, column_names: &[String], values: &[Box<dyn Value>]) -> String {
+    let mut message = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        message.push_str(&rest[..start]);
+        let after_brace = &rest[start + 1..];
+
+        let column_value = after_brace.find('}').and_then(|end| {
+            let column = &after_brace[..end];
+            let index = column_names.iter().position(|name| name == column)?;
+            Some((values.get(index)?.literal(), end))
+        });
+
+        match column_value {
+            Some((literal, end)) => {
+                message.push_str(&literal);
+                rest = &after_brace[end + 1..];
+            }
+            None => {
+                message.push('{');
+                rest = after_brace;
+            }
+        }
+    }
+    message.push_str(rest);
+    message
+}
+
+/// Rules and the findings of running them on the modules
+pub struct CheckResult {
+    pub rules: Vec<Rule>,
+    pub findings: Vec<Finding>,
+}
+
+impl CheckResult {
+    /// Return the exit code of `llql check`, 1 if any finding has `fail_on` severity or
+    /// higher, otherwise 0
+    pub fn exit_code(&self, fail_on: Severity) -> i32 {
+        if self
+            .findings
+            .iter()
+            .any(|finding| finding.severity >= fail_on)
+        {
+            return 1;
+        }
+        0
+    }
+}
+
+/// Read the rules file, load the modules and run the rules on them
+pub fn run_check(
+    rules_file: &str,
+    files: &[String],
+    options: &LoadOptions,
+) -> Result<CheckResult, String> {
+    let content = fs::read_to_string(rules_file)
+        .map_err(|error| format!("Can't read rules file {rules_file}: {error}"))?;
+    let rules = parse_rules(&content)?;
+
+    validate_files_paths(files)?;
+    let store = ModuleStore::load_with_options(files, options)?.into_shared();
+
+    let mut session = LLQLSession::with_jobs(store, options.jobs);
+    let findings = check_rules(&mut session, &rules)?;
+    Ok(CheckResult { rules, findings })
+}
+
+#[cfg(test)]
+mod tests {
+    use gitql_core::values::text::TextValue;
+
+    use super::*;
+    use crate::test_utils;
+
+    /// Write the rules to temporary file and return the exit code of checking the sample module
+    fn check_exit_code(name: &str, rules: &str, fail_on: Severity) -> i32 {
+        let rules_file =
+            std::env::temp_dir().join(format!("llql-check-{}-{name}.sql", std::process::id()));
+        fs::write(&rules_file, rules).unwrap();
+
+        let files = vec![test_utils::fixture_path("sample.ll")];
+        let check = run_check(
+            &rules_file.to_string_lossy(),
+            &files,
+            &LoadOptions::default(),
+        );
+        let _ = fs::remove_file(&rules_file);
+
+        match check {
+            Ok(check) => check.exit_code(fail_on),
+            Err(_) => CHECK_ERROR_EXIT_CODE,
+        }
+    }
+
+    #[test]
+    fn test_parse_valid_rules() {
+        let content = "-- Kernel rules\n\
+                       -- @rule no-alloca\n\
+                       -- @severity error\n\
+                       -- @message Call to alloca in {function_name}\n\
+                       SELECT function_name FROM instructions\n\
+                       WHERE m_inst(instruction, m_call('alloca'));\n\
+                       \n\
+                       -- @rule no-div\n\
+                       SELECT instruction FROM instructions WHERE m_inst(instruction, m_div());\n";
+
+        let rules = parse_rules(content).unwrap();
+        assert_eq!(rules.len(), 2);
+
+        assert_eq!(rules[0].name, "no-alloca");
+        assert_eq!(rules[0].severity, Severity::Error);
+        assert_eq!(rules[0].message, "Call to alloca in {function_name}");
+        assert!(rules[0].query.contains("m_call('alloca')"));
+
+        assert_eq!(rules[1].name, "no-div");
+        assert_eq!(rules[1].severity, Severity::Warning);
+        assert_eq!(rules[1].message, "no-div");
+    }
+
+    #[test]
+    fn test_parse_invalid_rules() {
+        let error = parse_rules("SELECT * FROM functions").err().unwrap();
+        assert_eq!(error, "Line 1: Query must follow `-- @rule` name");
+
+        let error = parse_rules("-- @rule a\n-- @severity fatal\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error,
+            "Line 2: Unknown severity `fatal`, expect note, warning or error"
+        );
+
+        let error = parse_rules("-- @rule a\nSELECT 1\n-- @rule a\n")
+            .err()
+            .unwrap();
+        assert_eq!(error, "Line 3: Rule `a` is already defined");
+
+        let error = parse_rules("-- @rule a\n").err().unwrap();
+        assert_eq!(error, "Rule `a` has no query");
+    }
+
+    #[test]
+    fn test_format_message_single_pass() {
+        let column_names = vec!["a".to_string(), "b".to_string()];
+        let values: Vec<Box<dyn Value>> = vec![
+            Box::new(TextValue::new("{b}".to_string())),
+            Box::new(TextValue::new("x".to_string())),
+        ];
+
+        assert_eq!(
+            format_message("{a} and {b}", &column_names, &values),
+            "{b} and x"
+        );
+        assert_eq!(format_message("{c} {a", &column_names, &values), "{c} {a");
+        assert_eq!(format_message("{{b}}", &column_names, &values), "{x}");
+    }
+
+    #[test]
+    fn test_check_exit_codes() {
+        let malloc_rule = "-- @rule malloc-call\n\
+                           -- @severity warning\n\
+                           -- @message Call to malloc in {function_name}\n\
+                           SELECT function_name FROM instructions\n\
+                           WHERE m_inst(instruction, m_call('malloc'));\n";
+        assert_eq!(
+            check_exit_code("warning", malloc_rule, Severity::Warning),
+            1
+        );
+        assert_eq!(check_exit_code("error", malloc_rule, Severity::Error), 0);
+
+        let no_findings_rule = "-- @rule free-call\n\
+                                SELECT function_name FROM instructions\n\
+                                WHERE m_inst(instruction, m_call('free'));\n";
+        assert_eq!(
+            check_exit_code("empty", no_findings_rule, Severity::Note),
+            0
+        );
+
+        let invalid_rule = "-- @rule invalid\nSELECT * FROM unknown_table;\n";
+        assert_eq!(
+            check_exit_code("invalid", invalid_rule, Severity::Note),
+            CHECK_ERROR_EXIT_CODE
+        );
+        assert_eq!(
+            check_exit_code("parse", "SELECT 1;\n", Severity::Note),
+            CHECK_ERROR_EXIT_CODE
+        );
+    }
+
+    #[test]
+    fn test_check_findings_message() {
+        let rules = parse_rules(
+            "-- @rule malloc-call\n\
+             -- @message Call to malloc in {function_name}\n\
+             SELECT function_name FROM instructions WHERE m_inst(instruction, m_call('malloc'));\n",
+        )
+        .unwrap();
+
+        let store = ModuleStore::load(&[test_utils::fixture_path("sample.ll")])
+            .unwrap()
+            .into_shared();
+        let mut session = LLQLSession::with_jobs(store, 1);
+        let findings = check_rules(&mut session, &rules).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].message, "Call to malloc in loop");
+    }
+
+    #[test]
+    fn test_severity_order() {
+        assert!(Severity::Note < Severity::Warning);
+        assert!(Severity::Warning < Severity::Error);
+        assert_eq!(Severity::parse("ERROR"), Some(Severity::Error));
+    }
+}
@@ -0,0 +1,527 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use gitql_ast::types::array::ArrayType;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::optional::OptionType;
+use gitql_ast::types::text::TextType;
+use gitql_core::signature::Signature;
+use gitql_core::signature::StandardFunction;
+use gitql_core::values::array::ArrayValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::null::NullValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+use inkwell::llvm_sys::core::LLVMConstIntGetSExtValue;
+use inkwell::llvm_sys::core::LLVMCountBasicBlocks;
+use inkwell::llvm_sys::core::LLVMGetBasicBlockParent;
+use inkwell::llvm_sys::core::LLVMGetBasicBlockTerminator;
+use inkwell::llvm_sys::core::LLVMGetCalledValue;
+use inkwell::llvm_sys::core::LLVMGetDebugLocLine;
+use inkwell::llvm_sys::core::LLVMGetEntryBasicBlock;
+use inkwell::llvm_sys::core::LLVMGetFirstBasicBlock;
+use inkwell::llvm_sys::core::LLVMGetFirstUse;
+use inkwell::llvm_sys::core::LLVMGetInstructionOpcode;
+use inkwell::llvm_sys::core::LLVMGetNextBasicBlock;
+use inkwell::llvm_sys::core::LLVMGetNextUse;
+use inkwell::llvm_sys::core::LLVMGetNumOperands;
+use inkwell::llvm_sys::core::LLVMGetNumSuccessors;
+use inkwell::llvm_sys::core::LLVMGetOperand;
+use inkwell::llvm_sys::core::LLVMGetSuccessor;
+use inkwell::llvm_sys::core::LLVMIsACallInst;
+use inkwell::llvm_sys::core::LLVMIsAConstantInt;
+use inkwell::llvm_sys::core::LLVMIsAFunction;
+use inkwell::llvm_sys::core::LLVMIsAInstruction;
+use inkwell::llvm_sys::core::LLVMIsAInvokeInst;
+use inkwell::llvm_sys::core::LLVMIsAUser;
+use inkwell::llvm_sys::core::LLVMTypeOf;
+use inkwell::llvm_sys::core::LLVMVoidType;
+use inkwell::llvm_sys::prelude::LLVMBasicBlockRef;
+use inkwell::llvm_sys::prelude::LLVMValueRef;
+
+use crate::ir::module_store::ModuleStore;
+use crate::ir::types::LLVMBasicBlockType;
+use crate::ir::types::LLVMDataType;
+use crate::ir::types::LLVMFunctionType;
+use crate::ir::types::LLVMInstType;
+use crate::ir::types::NullableType;
+use crate::ir::values::LLVMBasicBlockValue;
+use crate::ir::values::LLVMFunctionValue;
+use crate::ir::values::LLVMInstValue;
+use crate::ir::values::LLVMTypeValue;
+use crate::matchers::matchers_helper::instruction_parent_block;
+use crate::matchers::matchers_helper::llvm_opcode_name;
+use crate::matchers::matchers_helper::llvm_value_name;
+
+#[inline(always)]
+pub fn register_accessors_functions(map: &mut HashMap<&'static str, StandardFunction>) {
+    map.insert("opcode", inst_opcode);
+    map.insert("inst_name", inst_name);
+    map.insert("type_of", inst_type_of);
+    map.insert("operand", inst_operand);
+    map.insert("num_operands", inst_num_operands);
+    map.insert("num_uses", inst_num_uses);
+    map.insert("const_int_value", const_int_value);
+    map.insert("callee_name", inst_callee_name);
+    map.insert("dbg_line", inst_debug_line);
+    map.insert("parent_block", inst_parent_block);
+    map.insert("parent_function", inst_parent_function);
+    map.insert("block_count", function_block_count);
+    map.insert("entry_block", function_entry_block);
+    map.insert("terminator", block_terminator);
+    map.insert("predecessors", block_predecessors);
+}
+
+#[inline(always)]
+pub fn register_accessors_function_signatures(map: &mut HashMap<&'static str, Signature>) {
+    map.insert(
+        "opcode",
+        Signature {
+            parameters: vec![Box::new(LLVMInstType)],
+            return_type: Box::new(NullableType::new(Box::new(TextType))),
+        },
+    );
+
+    map.insert(
+        "inst_name",
+        Signature {
+            parameters: vec![Box::new(LLVMInstType)],
+            return_type: Box::new(TextType),
+        },
+    );
+
+    map.insert(
+        "type_of",
+        Signature {
+            parameters: vec![Box::new(LLVMInstType)],
+            return_type: Box::new(LLVMDataType),
+        },
+    );
+
+    map.insert(
+        "operand",
+        Signature {
+            parameters: vec![Box::new(LLVMInstType), Box::new(IntType)],
+            return_type: Box::new(OptionType::new(Some(Box::new(LLVMInstType)))),
+        },
+    );
+
+    map.insert(
+        "num_operands",
+        Signature {
+            parameters: vec![Box::new(LLVMInstType)],
+            return_type: Box::new(IntType),
+        },
+    );
+
+    map.insert(
+        "num_uses",
+        Signature {
+            parameters: vec![Box::new(LLVMInstType)],
+            return_type: Box::new(IntType),
+        },
+    );
+
+    map.insert(
+        "const_int_value",
+        Signature {
+            parameters: vec![Box::new(LLVMInstType)],
+            return_type: Box::new(NullableType::new(Box::new(IntType))),
+        },
+    );
+
+    map.insert(
+        "callee_name",
+        Signature {
+            parameters: vec![Box::new(LLVMInstType)],
+            return_type: Box::new(NullableType::new(Box::new(TextType))),
+        },
+    );
+
+    map.insert(
+        "dbg_line",
+        Signature {
+            parameters: vec![Box::new(LLVMInstType)],
+            return_type: Box::new(IntType),
+        },
+    );
+
+    map.insert(
+        "parent_block",
+        Signature {
+            parameters: vec![Box::new(LLVMInstType)],
+            return_type: Box::new(OptionType::new(Some(Box::new(LLVMBasicBlockType)))),
+        },
+    );
+
+    map.insert(
+        "parent_function",
+        Signature {
+            parameters: vec![Box::new(LLVMInstType)],
+            return_type: Box::new(OptionType::new(Some(Box::new(LLVMFunctionType)))),
+        },
+    );
+
+    map.insert(
+        "block_count",
+        Signature {
+            parameters: vec![Box::new(LLVMFunctionType)],
+            return_type: Box::new(IntType),
+        },
+    );
+
+    map.insert(
+        "entry_block",
+        Signature {
+            parameters: vec![Box::new(LLVMFunctionType)],
+            return_type: Box::new(OptionType::new(Some(Box::new(LLVMBasicBlockType)))),
+        },
+    );
+
+    map.insert(
+        "terminator",
+        Signature {
+            parameters: vec![Box::new(LLVMBasicBlockType)],
+            return_type: Box::new(OptionType::new(Some(Box::new(LLVMInstType)))),
+        },
+    );
+
+    map.insert(
+        "predecessors",
+        Signature {
+            parameters: vec![Box::new(LLVMBasicBlockType)],
+            return_type: Box::new(ArrayType::new(Box::new(LLVMBasicBlockType))),
+        },
+    );
+}
+
+/// Return the module store of the first argument to be shared with the returned values
+fn store_argument(values: &[Box<dyn Value>]) -> Arc<ModuleStore> {
+    let value = values[0].as_any();
+    if let Some(inst) = value.downcast_ref::<LLVMInstValue>() {
+        return inst.store.clone();
+    }
+
+    if let Some(function) = value.downcast_ref::<LLVMFunctionValue>() {
+        return function.store.clone();
+    }
+
+    value
+        .downcast_ref::<LLVMBasicBlockValue>()
+        .unwrap()
+        .store
+        .clone()
+}
+
+/// Return the LLVM value of the first argument, or `None` if it's null value returned
+/// from accessor such as `operand`
+fn llvm_value_argument(values: &[Box<dyn Value>]) -> Option<LLVMValueRef> {
+    values[0]
+        .as_any()
+        .downcast_ref::<LLVMInstValue>()
+        .map(|inst| inst.llvm_value)
+}
+
+fn text_value(text: String) -> Box<dyn Value> {
+    Box::new(TextValue::new(text))
+}
+
+fn int_value(value: i64) -> Box<dyn Value> {
+    Box::new(IntValue { value })
+}
+
+fn inst_opcode(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let Some(value) = llvm_value_argument(values) else {
+        return Box::new(NullValue);
+    };
+
+    unsafe {
+        // Arguments and constants has no opcode
+        if LLVMIsAInstruction(value).is_null() {
+            return Box::new(NullValue);
+        }
+
+        let opcode = LLVMGetInstructionOpcode(value);
+        text_value(llvm_opcode_name(opcode))
+    }
+}
+
+fn inst_name(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let name = llvm_value_argument(values)
+        .map(|value| llvm_value_name(&value))
+        .unwrap_or_default();
+    text_value(name)
+}
+
+fn inst_type_of(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    match llvm_value_argument(values) {
+        Some(value) => {
+            let llvm_type = unsafe { LLVMTypeOf(value) };
+            Box::new(LLVMTypeValue::new(llvm_type, store_argument(values)))
+        }
+        // Null value has no type, use `void` from the global context which lives forever
+        None => {
+            let void_type = unsafe { LLVMVoidType() };
+            let store = ModuleStore::default().into_shared();
+            Box::new(LLVMTypeValue::new(void_type, store))
+        }
+    }
+}
+
+fn inst_operand(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let Some(value) = llvm_value_argument(values) else {
+        return Box::new(NullValue);
+    };
+
+    let index = values[1].as_int().unwrap();
+    unsafe {
+        if LLVMIsAUser(value).is_null() || index < 0 || index >= LLVMGetNumOperands(value) as i64 {
+            return Box::new(NullValue);
+        }
+
+        let operand = LLVMGetOperand(value, index as u32);
+        Box::new(LLVMInstValue::new(operand, store_argument(values)))
+    }
+}
+
+fn inst_num_operands(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let Some(value) = llvm_value_argument(values) else {
+        return int_value(0);
+    };
+
+    unsafe {
+        if LLVMIsAUser(value).is_null() {
+            return int_value(0);
+        }
+
+        int_value(LLVMGetNumOperands(value) as i64)
+    }
+}
+
+fn inst_num_uses(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let Some(value) = llvm_value_argument(values) else {
+        return int_value(0);
+    };
+
+    let mut count = 0;
+    unsafe {
+        let mut next_use = LLVMGetFirstUse(value);
+        while !next_use.is_null() {
+            count += 1;
+            next_use = LLVMGetNextUse(next_use);
+        }
+    }
+    int_value(count)
+}
+
+fn const_int_value(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let Some(value) = llvm_value_argument(values) else {
+        return Box::new(NullValue);
+    };
+
+    unsafe {
+        if LLVMIsAConstantInt(value).is_null() {
+            return Box::new(NullValue);
+        }
+
+        int_value(LLVMConstIntGetSExtValue(value))
+    }
+}
+
+fn inst_callee_name(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let Some(value) = llvm_value_argument(values) else {
+        return Box::new(NullValue);
+    };
+
+    unsafe {
+        if LLVMIsACallInst(value).is_null() && LLVMIsAInvokeInst(value).is_null() {
+            return Box::new(NullValue);
+        }
+
+        // Indirect calls has no called function
+        let called_value = LLVMGetCalledValue(value);
+        if LLVMIsAFunction(called_value).is_null() {
+            return Box::new(NullValue);
+        }
+
+        text_value(llvm_value_name(&called_value))
+    }
+}
+
+fn inst_debug_line(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let Some(value) = llvm_value_argument(values) else {
+        return int_value(0);
+    };
+
+    unsafe {
+        if LLVMIsAInstruction(value).is_null() {
+            return int_value(0);
+        }
+
+        // Line zero means that instruction has no debug location
+        int_value(LLVMGetDebugLocLine(value) as i64)
+    }
+}
+
+fn inst_parent_block(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let basic_block =
+        llvm_value_argument(values).and_then(|value| instruction_parent_block(&value));
+    match basic_block {
+        Some(basic_block) => Box::new(LLVMBasicBlockValue::new(
+            basic_block,
+            store_argument(values),
+        )),
+        None => Box::new(NullValue),
+    }
+}
+
+fn inst_parent_function(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let basic_block =
+        llvm_value_argument(values).and_then(|value| instruction_parent_block(&value));
+    match basic_block {
+        Some(basic_block) => {
+            let llvm_function = unsafe { LLVMGetBasicBlockParent(basic_block) };
+            Box::new(LLVMFunctionValue::new(
+                llvm_function,
+                store_argument(values),
+            ))
+        }
+        None => Box::new(NullValue),
+    }
+}
+
+fn function_argument(values: &[Box<dyn Value>]) -> Option<LLVMValueRef> {
+    values[0]
+        .as_any()
+        .downcast_ref::<LLVMFunctionValue>()
+        .map(|function| function.llvm_function)
+}
+
+fn basic_block_argument(values: &[Box<dyn Value>]) -> Option<LLVMBasicBlockRef> {
+    values[0]
+        .as_any()
+        .downcast_ref::<LLVMBasicBlockValue>()
+        .map(|basic_block| basic_block.basic_block)
+}
+
+fn function_block_count(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let count = function_argument(values)
+        .map(|function| unsafe { LLVMCountBasicBlocks(function) } as i64)
+        .unwrap_or_default();
+    int_value(count)
+}
+
+fn function_entry_block(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let Some(function) = function_argument(values) else {
+        return Box::new(NullValue);
+    };
+
+    unsafe {
+        // Declarations has no basic blocks
+        if LLVMCountBasicBlocks(function) == 0 {
+            return Box::new(NullValue);
+        }
+
+        let basic_block = LLVMGetEntryBasicBlock(function);
+        Box::new(LLVMBasicBlockValue::new(
+            basic_block,
+            store_argument(values),
+        ))
+    }
+}
+
+fn block_terminator(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let Some(basic_block) = basic_block_argument(values) else {
+        return Box::new(NullValue);
+    };
+
+    let terminator = unsafe { LLVMGetBasicBlockTerminator(basic_block) };
+    if terminator.is_null() {
+        return Box::new(NullValue);
+    }
+    Box::new(LLVMInstValue::new(terminator, store_argument(values)))
+}
+
+fn block_predecessors(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let mut predecessors: Vec<Box<dyn Value>> = vec![];
+    if let Some(basic_block) = basic_block_argument(values) {
+        unsafe {
+            let function = LLVMGetBasicBlockParent(basic_block);
+            let mut current_block = LLVMGetFirstBasicBlock(function);
+            while !current_block.is_null() {
+                let terminator = LLVMGetBasicBlockTerminator(current_block);
+                if !terminator.is_null() {
+                    let successors_count = LLVMGetNumSuccessors(terminator);
+                    let is_predecessor = (0..successors_count)
+                        .any(|i| LLVMGetSuccessor(terminator, i) == basic_block);
+                    if is_predecessor {
+                        let store = store_argument(values);
+                        predecessors.push(Box::new(LLVMBasicBlockValue::new(current_block, store)));
+                    }
+                }
+                current_block = LLVMGetNextBasicBlock(current_block);
+            }
+        }
+    }
+
+    Box::new(ArrayValue {
+        values: predecessors,
+        base_type: Box::new(LLVMBasicBlockType),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils;
+
+    fn query_rows(query: &str) -> Vec<Vec<String>> {
+        test_utils::query_rows("sample.ll", query)
+    }
+
+    #[test]
+    fn test_accessors_typed_defaults() {
+        // `%mul = mul i32 %a, 3` is not call, has no debug location and its operands are
+        // argument and constant
+        let rows = query_rows(
+            "SELECT opcode(instruction), callee_name(instruction), dbg_line(instruction), \
+             const_int_value(instruction), opcode(operand(instruction, 0)), \
+             const_int_value(operand(instruction, 1)) \
+             FROM instructions WHERE function_name = 'add_mul' LIMIT 1",
+        );
+        assert_eq!(rows, [["mul", "Null", "0", "Null", "Null", "3"]]);
+    }
+
+    #[test]
+    fn test_accessors_nullable_comparisons() {
+        let rows = query_rows(
+            "SELECT opcode(instruction), callee_name(instruction) FROM instructions \
+             WHERE opcode(instruction) = 'call' AND (callee_name(instruction) IS NOT NULL)",
+        );
+        assert_eq!(rows, [["call", "malloc"]]);
+
+        let rows = query_rows(
+            "SELECT const_int_value(operand(instruction, 1)) FROM instructions \
+             WHERE ifnull(const_int_value(operand(instruction, 1)), 0) > 2",
+        );
+        assert_eq!(rows, [["3"]]);
+    }
+
+    #[test]
+    fn test_accessors_null_values() {
+        let rows = query_rows(
+            "SELECT opcode(operand(instruction, 9)), inst_name(operand(instruction, 9)), \
+             num_operands(operand(instruction, 9)), type_of(operand(instruction, 9)), \
+             m_inst(operand(instruction, 9), m_any_inst()), \
+             f_match(parent_function(operand(instruction, 0)), m_function()) \
+             FROM instructions WHERE function_name = 'add_mul' LIMIT 1",
+        );
+        assert_eq!(rows, [["Null", "", "0", "void", "false", "false"]]);
+
+        let rows = query_rows(
+            "SELECT block_count(function), terminator(entry_block(function)) IS NULL, \
+             predecessors(entry_block(function)) \
+             FROM functions WHERE function_name = 'malloc'",
+        );
+        assert_eq!(rows, [["0", "true", "[]"]]);
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use gitql_ast::types::optional::OptionType;
+use gitql_ast::types::text::TextType;
+use gitql_ast::types::varargs::VarargsType;
+use gitql_core::signature::Signature;
+use gitql_core::signature::StandardFunction;
+use gitql_core::values::Value;
+
+use crate::functions::matcher_signature_without_parameters;
+use crate::ir::types::InstMatcherType;
+use crate::ir::values::InstMatcherValue;
+use crate::matchers::context::InBasicBlockMatcher;
+use crate::matchers::context::InFunctionMatcher;
+
+#[inline(always)]
+pub fn register_context_matchers_functions(map: &mut HashMap<&'static str, StandardFunction>) {
+    map.insert("m_in_function", match_in_function);
+    map.insert("m_in_block", match_in_block);
+    map.insert("m_in_entry_block", match_in_entry_block);
+    map.insert("m_in_unreachable_block", match_in_unreachable_block);
+}
+
+#[inline(always)]
+pub fn register_context_matchers_function_signatures(map: &mut HashMap<&'static str, Signature>) {
+    map.insert(
+        "m_in_function",
+        Signature {
+            parameters: vec![
+                Box::new(OptionType::new(Some(Box::new(TextType)))),
+                Box::new(VarargsType::new(Box::new(TextType))),
+            ],
+            return_type: Box::new(InstMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_in_block",
+        Signature::with_return(Box::new(InstMatcherType))
+            .add_parameter(Box::new(OptionType::new(Some(Box::new(TextType))))),
+    );
+
+    map.insert("m_in_entry_block", matcher_signature_without_parameters());
+    map.insert(
+        "m_in_unreachable_block",
+        matcher_signature_without_parameters(),
+    );
+}
+
+fn match_in_function(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let name_pattern = if values.is_empty() {
+        None
+    } else {
+        values[0].as_text()
+    };
+
+    let attributes: Vec<String> = values
+        .iter()
+        .skip(1)
+        .filter_map(|value| value.as_text())
+        .collect();
+
+    let matcher = Box::new(InFunctionMatcher {
+        name_pattern,
+        attributes,
+    });
+    Box::new(InstMatcherValue { matcher })
+}
+
+fn match_in_block(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let name_pattern = if values.is_empty() {
+        None
+    } else {
+        values[0].as_text()
+    };
+
+    let matcher = Box::new(InBasicBlockMatcher::create_in_block(name_pattern));
+    Box::new(InstMatcherValue { matcher })
+}
+
+fn match_in_entry_block(_values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let matcher = Box::new(InBasicBlockMatcher::create_in_entry_block());
+    Box::new(InstMatcherValue { matcher })
+}
+
+fn match_in_unreachable_block(_values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let matcher = Box::new(InBasicBlockMatcher::create_in_unreachable_block());
+    Box::new(InstMatcherValue { matcher })
+}
@@ -1,13 +1,23 @@
 use std::collections::HashMap;
 
+use crate::functions::single_optional_matcher_value;
 use crate::ir::types::InstMatcherType;
 use crate::ir::values::InstMatcherValue;
+use crate::matchers::exception::CatchPadInstMatcher;
+use crate::matchers::exception::CatchReturnInstMatcher;
+use crate::matchers::exception::CatchSwitchInstMatcher;
+use crate::matchers::exception::CleanupPadInstMatcher;
+use crate::matchers::exception::CleanupReturnInstMatcher;
 use crate::matchers::exception::InvokeInstMatcher;
 use crate::matchers::exception::LandingPadInstMatcher;
 use crate::matchers::exception::ResumeInstMatcher;
 use crate::matchers::exception::RethrowInstMatcher;
 use crate::matchers::exception::ThrowInstMatcher;
 
+use gitql_ast::types::boolean::BoolType;
+use gitql_ast::types::optional::OptionType;
+use gitql_ast::types::text::TextType;
+use gitql_ast::types::varargs::VarargsType;
 use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
 use gitql_core::values::Value;
@@ -21,6 +31,12 @@ pub fn register_exception_inst_matchers_functions(
     map.insert("m_resume", match_resume_inst);
     map.insert("m_throw", match_throw_inst);
     map.insert("m_rethrow", match_rethrow_inst);
+
+    map.insert("m_catchswitch", match_catchswitch_inst);
+    map.insert("m_catchpad", match_catchpad_inst);
+    map.insert("m_cleanuppad", match_cleanuppad_inst);
+    map.insert("m_catchret", match_catchret_inst);
+    map.insert("m_cleanupret", match_cleanupret_inst);
 }
 
 #[inline(always)]
@@ -38,7 +54,10 @@ pub fn register_exception_inst_matchers_function_signatures(
     map.insert(
         "m_landingpad",
         Signature {
-            parameters: vec![],
+            parameters: vec![
+                Box::new(OptionType::new(Some(Box::new(BoolType)))),
+                Box::new(VarargsType::new(Box::new(TextType))),
+            ],
             return_type: Box::new(InstMatcherType),
         },
     );
@@ -66,6 +85,18 @@ pub fn register_exception_inst_matchers_function_signatures(
             return_type: Box::new(InstMatcherType),
         },
     );
+
+    map.insert("m_catchswitch", funclet_matcher_signature());
+    map.insert("m_catchpad", funclet_matcher_signature());
+    map.insert("m_cleanuppad", funclet_matcher_signature());
+    map.insert("m_catchret", funclet_matcher_signature());
+    map.insert("m_cleanupret", funclet_matcher_signature());
+}
+
+#[inline(always)]
+fn funclet_matcher_signature() -> Signature {
+    Signature::with_return(Box::new(InstMatcherType))
+        .add_parameter(Box::new(OptionType::new(Some(Box::new(InstMatcherType)))))
 }
 
 fn match_invoke_inst(_values: &[Box<dyn Value>]) -> Box<dyn Value> {
@@ -73,8 +104,22 @@ fn match_invoke_inst(_values: &[Box<dyn Value>]) -> Box<dyn Value> {
     Box::new(InstMatcherValue { matcher })
 }
 
-fn match_landingpad_inst(_values: &[Box<dyn Value>]) -> Box<dyn Value> {
-    let matcher = Box::new(LandingPadInstMatcher);
+fn match_landingpad_inst(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let is_cleanup = if values.is_empty() {
+        None
+    } else {
+        values[0].as_bool()
+    };
+
+    let type_infos: Vec<String> = values
+        .iter()
+        .skip(1)
+        .filter_map(|value| value.as_text())
+        .collect();
+
+    let matcher = Box::new(LandingPadInstMatcher::create_landingpad(
+        is_cleanup, type_infos,
+    ));
     Box::new(InstMatcherValue { matcher })
 }
 
@@ -92,3 +137,33 @@ fn match_rethrow_inst(_values: &[Box<dyn Value>]) -> Box<dyn Value> {
     let matcher = Box::new(RethrowInstMatcher);
     Box::new(InstMatcherValue { matcher })
 }
+
+fn match_catchswitch_inst(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let parent_matcher = single_optional_matcher_value(values);
+    let matcher = Box::new(CatchSwitchInstMatcher { parent_matcher });
+    Box::new(InstMatcherValue { matcher })
+}
+
+fn match_catchpad_inst(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let parent_matcher = single_optional_matcher_value(values);
+    let matcher = Box::new(CatchPadInstMatcher { parent_matcher });
+    Box::new(InstMatcherValue { matcher })
+}
+
+fn match_cleanuppad_inst(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let parent_matcher = single_optional_matcher_value(values);
+    let matcher = Box::new(CleanupPadInstMatcher { parent_matcher });
+    Box::new(InstMatcherValue { matcher })
+}
+
+fn match_catchret_inst(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let pad_matcher = single_optional_matcher_value(values);
+    let matcher = Box::new(CatchReturnInstMatcher { pad_matcher });
+    Box::new(InstMatcherValue { matcher })
+}
+
+fn match_cleanupret_inst(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let pad_matcher = single_optional_matcher_value(values);
+    let matcher = Box::new(CleanupReturnInstMatcher { pad_matcher });
+    Box::new(InstMatcherValue { matcher })
+}
@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+
+use gitql_ast::types::boolean::BoolType;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::optional::OptionType;
+use gitql_ast::types::text::TextType;
+use gitql_ast::types::varargs::VarargsType;
+use gitql_core::signature::Signature;
+use gitql_core::signature::StandardFunction;
+use gitql_core::values::boolean::BoolValue;
+use gitql_core::values::Value;
+
+use crate::ir::types::FunctionMatcherType;
+use crate::ir::types::InstMatcherType;
+use crate::ir::types::LLVMFunctionType;
+use crate::ir::types::TypeMatcherType;
+use crate::ir::values::FunctionMatcherValue;
+use crate::ir::values::InstMatcherValue;
+use crate::ir::values::LLVMFunctionValue;
+use crate::ir::values::TypeMatcherValue;
+use crate::matchers::function::FunctionArgumentsCountMatcher;
+use crate::matchers::function::FunctionArgumentsTypesMatcher;
+use crate::matchers::function::FunctionAttributesMatcher;
+use crate::matchers::function::FunctionBlocksCountMatcher;
+use crate::matchers::function::FunctionCombineMatcher;
+use crate::matchers::function::FunctionContainsMatcher;
+use crate::matchers::function::FunctionDeclarationMatcher;
+use crate::matchers::function::FunctionLinkageMatcher;
+use crate::matchers::function::FunctionMatcher;
+use crate::matchers::function::FunctionNotMatcher;
+use crate::matchers::function::LLVMFunction;
+use crate::matchers::Matcher;
+
+#[inline(always)]
+pub fn register_function_matchers_functions(map: &mut HashMap<&'static str, StandardFunction>) {
+    map.insert("f_match", match_function);
+    map.insert("m_function", match_function_matcher);
+    map.insert("m_fn_linkage", match_function_linkage);
+    map.insert("m_fn_attributes", match_function_attributes);
+    map.insert("m_fn_declaration", match_function_declaration);
+    map.insert("m_fn_args_count", match_function_arguments_count);
+    map.insert("m_fn_args_types", match_function_arguments_types);
+    map.insert("m_fn_blocks_count", match_function_blocks_count);
+    map.insert("m_fn_contains", match_function_contains);
+    map.insert("m_fn_not", match_function_not);
+    map.insert("m_fn_or", match_function_or);
+    map.insert("m_fn_and", match_function_and);
+}
+
+#[inline(always)]
+pub fn register_function_matchers_function_signatures(map: &mut HashMap<&'static str, Signature>) {
+    map.insert(
+        "f_match",
+        Signature {
+            parameters: vec![Box::new(LLVMFunctionType), Box::new(FunctionMatcherType)],
+            return_type: Box::new(BoolType),
+        },
+    );
+
+    map.insert(
+        "m_function",
+        Signature {
+            parameters: vec![
+                Box::new(OptionType::new(Some(Box::new(TextType)))),
+                Box::new(VarargsType::new(Box::new(FunctionMatcherType))),
+            ],
+            return_type: Box::new(FunctionMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_fn_linkage",
+        Signature {
+            parameters: vec![Box::new(TextType)],
+            return_type: Box::new(FunctionMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_fn_attributes",
+        Signature {
+            parameters: vec![
+                Box::new(TextType),
+                Box::new(VarargsType::new(Box::new(TextType))),
+            ],
+            return_type: Box::new(FunctionMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_fn_declaration",
+        Signature::with_return(Box::new(FunctionMatcherType)),
+    );
+
+    map.insert(
+        "m_fn_args_count",
+        Signature {
+            parameters: vec![Box::new(IntType)],
+            return_type: Box::new(FunctionMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_fn_args_types",
+        Signature {
+            parameters: vec![Box::new(VarargsType::new(Box::new(TypeMatcherType)))],
+            return_type: Box::new(FunctionMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_fn_blocks_count",
+        Signature {
+            parameters: vec![
+                Box::new(IntType),
+                Box::new(OptionType::new(Some(Box::new(IntType)))),
+            ],
+            return_type: Box::new(FunctionMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_fn_contains",
+        Signature {
+            parameters: vec![Box::new(InstMatcherType)],
+            return_type: Box::new(FunctionMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_fn_not",
+        Signature {
+            parameters: vec![Box::new(FunctionMatcherType)],
+            return_type: Box::new(FunctionMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_fn_or",
+        Signature {
+            parameters: vec![Box::new(FunctionMatcherType), Box::new(FunctionMatcherType)],
+            return_type: Box::new(FunctionMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_fn_and",
+        Signature {
+            parameters: vec![Box::new(FunctionMatcherType), Box::new(FunctionMatcherType)],
+            return_type: Box::new(FunctionMatcherType),
+        },
+    );
+}
+
+fn match_function(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    // Null value returned from accessor such as `parent_function` doesn't match any matcher
+    let Some(function) = values[0].as_any().downcast_ref::<LLVMFunctionValue>() else {
+        return Box::new(BoolValue { value: false });
+    };
+    let matcher = values[1]
+        .as_any()
+        .downcast_ref::<FunctionMatcherValue>()
+        .unwrap();
+
+    let is_match = LLVMFunction::new(function.llvm_function)
+        .is_some_and(|function| matcher.matcher.is_match(&function));
+    Box::new(BoolValue { value: is_match })
+}
+
+fn match_function_matcher(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let name_pattern = if values.is_empty() {
+        None
+    } else {
+        values[0].as_text()
+    };
+
+    let matchers = values
+        .iter()
+        .skip(1)
+        .filter_map(|value| value.as_any().downcast_ref::<FunctionMatcherValue>())
+        .map(|value| value.matcher.clone())
+        .collect();
+
+    let matcher = Box::new(FunctionMatcher {
+        name_pattern,
+        matchers,
+    });
+    Box::new(FunctionMatcherValue { matcher })
+}
+
+fn match_function_linkage(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let linkage = values[0].as_text().unwrap();
+    let matcher = Box::new(FunctionLinkageMatcher { linkage });
+    Box::new(FunctionMatcherValue { matcher })
+}
+
+fn match_function_attributes(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let attributes = values.iter().filter_map(|value| value.as_text()).collect();
+    let matcher = Box::new(FunctionAttributesMatcher { attributes });
+    Box::new(FunctionMatcherValue { matcher })
+}
+
+fn match_function_declaration(_values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let matcher = Box::new(FunctionDeclarationMatcher);
+    Box::new(FunctionMatcherValue { matcher })
+}
+
+fn match_function_arguments_count(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let count = values[0].as_int().unwrap().max(0) as u32;
+    let matcher = Box::new(FunctionArgumentsCountMatcher { count });
+    Box::new(FunctionMatcherValue { matcher })
+}
+
+fn match_function_arguments_types(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let matchers = values
+        .iter()
+        .filter_map(|value| value.as_any().downcast_ref::<TypeMatcherValue>())
+        .map(|value| value.matcher.clone())
+        .collect();
+
+    let matcher = Box::new(FunctionArgumentsTypesMatcher { matchers });
+    Box::new(FunctionMatcherValue { matcher })
+}
+
+fn match_function_blocks_count(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let min = values[0].as_int().unwrap().max(0) as u32;
+    let max = values
+        .get(1)
+        .and_then(|value| value.as_int())
+        .map(|max| max.max(0) as u32);
+
+    let matcher = Box::new(FunctionBlocksCountMatcher { min, max });
+    Box::new(FunctionMatcherValue { matcher })
+}
+
+fn match_function_contains(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let inst_matcher = values[0]
+        .as_any()
+        .downcast_ref::<InstMatcherValue>()
+        .unwrap()
+        .matcher
+        .clone();
+
+    let matcher = Box::new(FunctionContainsMatcher {
+        matcher: inst_matcher,
+    });
+    Box::new(FunctionMatcherValue { matcher })
+}
+
+fn match_function_not(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let matcher = Box::new(FunctionNotMatcher {
+        matcher: function_matcher(&values[0]),
+    });
+    Box::new(FunctionMatcherValue { matcher })
+}
+
+fn match_function_or(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let lhs = function_matcher(&values[0]);
+    let rhs = function_matcher(&values[1]);
+    let matcher = Box::new(FunctionCombineMatcher::create_or(lhs, rhs));
+    Box::new(FunctionMatcherValue { matcher })
+}
+
+fn match_function_and(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let lhs = function_matcher(&values[0]);
+    let rhs = function_matcher(&values[1]);
+    let matcher = Box::new(FunctionCombineMatcher::create_and(lhs, rhs));
+    Box::new(FunctionMatcherValue { matcher })
+}
+
+#[allow(clippy::borrowed_box)]
+fn function_matcher(value: &Box<dyn Value>) -> Box<dyn Matcher<LLVMFunction>> {
+    value
+        .as_any()
+        .downcast_ref::<FunctionMatcherValue>()
+        .unwrap()
+        .matcher
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils;
+
+    fn matched_functions(matcher: &str) -> Vec<String> {
+        let query =
+            format!("SELECT function_name FROM functions WHERE f_match(function, {matcher})");
+        test_utils::query_column("sample.ll", &query)
+    }
+
+    #[test]
+    fn test_function_matchers() {
+        assert_eq!(
+            matched_functions("m_function()"),
+            ["add_mul", "div", "malloc", "loop"]
+        );
+        assert_eq!(matched_functions("m_function('*_mul')"), ["add_mul"]);
+        assert_eq!(matched_functions("m_fn_linkage('internal')"), ["div"]);
+        assert_eq!(matched_functions("m_fn_attributes('noinline')"), ["div"]);
+        assert_eq!(matched_functions("m_fn_declaration()"), ["malloc"]);
+        assert_eq!(matched_functions("m_fn_args_count(2)"), ["add_mul", "div"]);
+        assert_eq!(matched_functions("m_fn_args_types(m_int32())"), ["loop"]);
+        assert_eq!(matched_functions("m_fn_blocks_count(2)"), ["loop"]);
+        assert_eq!(
+            matched_functions("m_fn_blocks_count(0, 1)"),
+            ["add_mul", "div", "malloc"]
+        );
+        assert_eq!(
+            matched_functions("m_fn_contains(m_call('malloc'))"),
+            ["loop"]
+        );
+        assert!(matched_functions("m_function('main')").is_empty());
+    }
+
+    #[test]
+    fn test_function_combine_matchers() {
+        assert_eq!(
+            matched_functions("m_fn_not(m_fn_declaration())"),
+            ["add_mul", "div", "loop"]
+        );
+        assert_eq!(
+            matched_functions("m_fn_or(m_fn_declaration(), m_fn_linkage('internal'))"),
+            ["div", "malloc"]
+        );
+        assert_eq!(
+            matched_functions("m_fn_and(m_fn_args_count(2), m_fn_linkage('internal'))"),
+            ["div"]
+        );
+    }
+
+    #[test]
+    fn test_function_matcher_literal() {
+        let query = "SELECT m_fn_and(m_fn_not(m_fn_declaration()), m_fn_or(m_fn_args_count(1), m_fn_linkage('internal')))";
+        assert_eq!(
+            test_utils::query_column("sample.ll", query),
+            ["m_fn_and(m_fn_not(m_fn_declaration()), m_fn_or(m_fn_args_count(1), m_fn_linkage(\"internal\")))"]
+        );
+    }
+
+    #[test]
+    fn test_functions_table() {
+        let query = "SELECT function_name, basic_blocks_count, instructions_count FROM functions";
+        let rows = test_utils::query_rows("sample.ll", query);
+        assert_eq!(
+            rows,
+            [
+                ["add_mul", "1", "3"],
+                ["div", "1", "2"],
+                ["malloc", "0", "0"],
+                ["loop", "3", "8"],
+            ]
+        );
+    }
+}
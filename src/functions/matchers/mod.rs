@@ -4,13 +4,16 @@ pub mod call;
 pub mod cast;
 pub mod combine;
 pub mod constants;
+pub mod context;
 pub mod debug;
 pub mod exception;
 pub mod fcmp;
+pub mod function;
 pub mod get_element_ptr;
 pub mod icmp;
 pub mod operand_bundle;
 pub mod other;
+pub mod pattern;
 pub mod shifts;
 pub mod types;
 pub mod usage;
@@ -19,10 +19,13 @@ use crate::ir::values::LLVMInstValue;
 use crate::ir::values::TypeMatcherValue;
 use crate::matchers::other::AnyInstMatcher;
 use crate::matchers::other::ArgumentMatcher;
+use crate::matchers::other::ContainsMatcher;
 use crate::matchers::other::ExtractValueInstMatcher;
 use crate::matchers::other::InstTypeMatcher;
 use crate::matchers::other::LabelInstMatcher;
 use crate::matchers::other::OperandCountMatcher;
+use crate::matchers::other::OperandMatcher;
+use crate::matchers::other::OperandsMatcher;
 use crate::matchers::other::PoisonValueMatcher;
 use crate::matchers::other::ReturnInstMatcher;
 use crate::matchers::other::UnreachableInstMatcher;
@@ -39,6 +42,10 @@ pub fn register_other_inst_matchers_functions(map: &mut HashMap<&'static str, St
     map.insert("m_return", match_return_inst);
     map.insert("m_unreachable", match_unreachable_inst);
     map.insert("m_operands_number", match_operands_number);
+    map.insert("m_operand", match_operand);
+    map.insert("m_any_operand", match_any_operand);
+    map.insert("m_all_operands", match_all_operands);
+    map.insert("m_contains", match_contains);
 }
 
 #[inline(always)]
@@ -127,10 +134,46 @@ pub fn register_other_inst_matchers_function_signatures(
         "m_operands_number",
         Signature::with_return(Box::new(InstMatcherType)).add_parameter(Box::new(IntType)),
     );
+
+    map.insert(
+        "m_operand",
+        Signature {
+            parameters: vec![Box::new(IntType), Box::new(InstMatcherType)],
+            return_type: Box::new(InstMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_any_operand",
+        Signature {
+            parameters: vec![Box::new(InstMatcherType)],
+            return_type: Box::new(InstMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_all_operands",
+        Signature {
+            parameters: vec![Box::new(InstMatcherType)],
+            return_type: Box::new(InstMatcherType),
+        },
+    );
+
+    map.insert(
+        "m_contains",
+        Signature {
+            parameters: vec![Box::new(InstMatcherType), Box::new(IntType)],
+            return_type: Box::new(InstMatcherType),
+        },
+    );
 }
 
 fn match_inst(values: &[Box<dyn Value>]) -> Box<dyn Value> {
-    let inst = values[0].as_any().downcast_ref::<LLVMInstValue>().unwrap();
+    // Null value returned from accessor such as `operand` doesn't match any matcher
+    let Some(inst) = values[0].as_any().downcast_ref::<LLVMInstValue>() else {
+        return Box::new(BoolValue { value: false });
+    };
+
     let matcher = values[1]
         .as_any()
         .downcast_ref::<InstMatcherValue>()
@@ -260,3 +303,63 @@ fn match_operands_number(values: &[Box<dyn Value>]) -> Box<dyn Value> {
     let matcher = Box::new(OperandCountMatcher::has_n_operands(expected_number));
     Box::new(InstMatcherValue { matcher })
 }
+
+fn match_operand(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let index = values[0].as_int().unwrap();
+    let matcher = values[1]
+        .as_any()
+        .downcast_ref::<InstMatcherValue>()
+        .unwrap()
+        .matcher
+        .clone();
+
+    // Negative index can't match any operand
+    let index = if index < 0 {
+        usize::MAX
+    } else {
+        index as usize
+    };
+    Box::new(InstMatcherValue {
+        matcher: Box::new(OperandMatcher { index, matcher }),
+    })
+}
+
+fn match_any_operand(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let matcher = values[0]
+        .as_any()
+        .downcast_ref::<InstMatcherValue>()
+        .unwrap()
+        .matcher
+        .clone();
+
+    Box::new(InstMatcherValue {
+        matcher: Box::new(OperandsMatcher::create_any_operand(matcher)),
+    })
+}
+
+fn match_all_operands(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let matcher = values[0]
+        .as_any()
+        .downcast_ref::<InstMatcherValue>()
+        .unwrap()
+        .matcher
+        .clone();
+
+    Box::new(InstMatcherValue {
+        matcher: Box::new(OperandsMatcher::create_all_operands(matcher)),
+    })
+}
+
+fn match_contains(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let matcher = values[0]
+        .as_any()
+        .downcast_ref::<InstMatcherValue>()
+        .unwrap()
+        .matcher
+        .clone();
+
+    let depth = values[1].as_int().unwrap().max(0) as usize;
+    Box::new(InstMatcherValue {
+        matcher: Box::new(ContainsMatcher { matcher, depth }),
+    })
+}
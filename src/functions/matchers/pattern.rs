@@ -0,0 +1,108 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use gitql_ast::types::text::TextType;
+use gitql_core::signature::Signature;
+use gitql_core::signature::StandardFunction;
+use gitql_core::values::Value;
+use inkwell::llvm_sys::prelude::LLVMValueRef;
+
+use crate::ir::types::InstMatcherType;
+use crate::ir::values::InstMatcherValue;
+use crate::matchers::combine::CombineUnaryInstMatcher;
+use crate::matchers::other::AnyInstMatcher;
+use crate::matchers::Matcher;
+use crate::pattern::compile_pattern;
+
+/// Maximum number of compiled patterns to keep, the cache is cleared when it is full
+const MAX_COMPILED_PATTERNS: usize = 64;
+
+thread_local! {
+    /// Compiled patterns, so the pattern is compiled only once per query not per row
+    static COMPILED_PATTERNS: RefCell<HashMap<String, Box<dyn Matcher<LLVMValueRef>>>> =
+        RefCell::new(HashMap::new());
+}
+
+#[inline(always)]
+pub fn register_pattern_matchers_functions(map: &mut HashMap<&'static str, StandardFunction>) {
+    map.insert("m_pattern", match_pattern);
+}
+
+#[inline(always)]
+pub fn register_pattern_matchers_function_signatures(map: &mut HashMap<&'static str, Signature>) {
+    map.insert(
+        "m_pattern",
+        Signature {
+            parameters: vec![Box::new(TextType)],
+            return_type: Box::new(InstMatcherType),
+        },
+    );
+}
+
+fn match_pattern(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let source = values[0].as_text().unwrap();
+
+    // The borrow is released before compiling, so the cache is never borrowed twice
+    let cached = COMPILED_PATTERNS.with(|patterns| patterns.borrow().get(&source).cloned());
+    if let Some(matcher) = cached {
+        return Box::new(InstMatcherValue { matcher });
+    }
+
+    let matcher = match compile_pattern(&source) {
+        Ok(matcher) => matcher,
+        Err(_) => {
+            // Patterns are validated by `validate_patterns` before the query is parsed, so it is
+            // reached only if the query skipped the validation, and it is matcher that match nothing
+            return Box::new(InstMatcherValue {
+                matcher: Box::new(CombineUnaryInstMatcher::create_not(Box::new(
+                    AnyInstMatcher,
+                ))),
+            });
+        }
+    };
+
+    COMPILED_PATTERNS.with(|patterns| {
+        let mut patterns = patterns.borrow_mut();
+        if patterns.len() >= MAX_COMPILED_PATTERNS {
+            patterns.clear();
+        }
+        patterns.insert(source, matcher.clone());
+    });
+
+    Box::new(InstMatcherValue { matcher })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::LLQLSession;
+    use crate::query::QueryError;
+    use crate::test_utils;
+
+    #[test]
+    fn test_match_pattern() {
+        let mut session = LLQLSession::load(&[test_utils::fixture_path("sample.ll")]).unwrap();
+        let query = r#"SELECT function_name FROM instructions WHERE m_inst(instruction, m_pattern("add(mul(_, #c), 0)"))"#;
+
+        // Second query uses the compiled pattern from the cache
+        for _ in 0..2 {
+            let results = session.query(query).unwrap();
+            assert_eq!(results[0].rows.len(), 1);
+            assert_eq!(results[0].rows[0][0].literal(), "add_mul");
+        }
+    }
+
+    #[test]
+    fn test_match_invalid_pattern() {
+        let mut session = LLQLSession::load(&[test_utils::fixture_path("sample.ll")]).unwrap();
+        let query = r#"SELECT instruction FROM instructions WHERE m_inst(instruction, m_pattern("add(foo, 0)"))"#;
+
+        let error = session.query(query).err().unwrap();
+        assert_eq!(
+            error,
+            QueryError::Syntax("Invalid pattern, Unknown matcher `foo`".to_string())
+        );
+
+        let rows = test_utils::query_rows("sample.ll", "SELECT function_name FROM functions");
+        assert_eq!(rows.len(), 4);
+    }
+}
@@ -6,6 +6,8 @@ use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
 use gitql_core::values::Value;
 
+use accessors::register_accessors_function_signatures;
+use accessors::register_accessors_functions;
 use gitql_std::standard::standard_function_signatures;
 use gitql_std::standard::standard_functions;
 use inkwell::llvm_sys::prelude::LLVMValueRef;
@@ -21,18 +23,24 @@ use matchers::combine::register_combine_matchers_function;
 use matchers::combine::register_combine_matchers_function_signatures;
 use matchers::constants::register_constants_matchers_function_signatures;
 use matchers::constants::register_constants_matchers_functions;
+use matchers::context::register_context_matchers_function_signatures;
+use matchers::context::register_context_matchers_functions;
 use matchers::debug::register_debug_inst_matchers_function_signatures;
 use matchers::debug::register_debug_inst_matchers_functions;
 use matchers::exception::register_exception_inst_matchers_function_signatures;
 use matchers::exception::register_exception_inst_matchers_functions;
 use matchers::fcmp::register_float_comparisons_matchers_function_signatures;
 use matchers::fcmp::register_float_comparisons_matchers_functions;
+use matchers::function::register_function_matchers_function_signatures;
+use matchers::function::register_function_matchers_functions;
 use matchers::icmp::register_int_comparisons_matchers_function_signatures;
 use matchers::icmp::register_int_comparisons_matchers_functions;
 use matchers::operand_bundle::register_operand_bundle_inst_matchers_function_signatures;
 use matchers::operand_bundle::register_operand_bundle_inst_matchers_functions;
 use matchers::other::register_other_inst_matchers_function_signatures;
 use matchers::other::register_other_inst_matchers_functions;
+use matchers::pattern::register_pattern_matchers_function_signatures;
+use matchers::pattern::register_pattern_matchers_functions;
 use matchers::shifts::register_shift_matchers_function_signatures;
 use matchers::shifts::register_shift_matchers_functions;
 use matchers::types::register_type_matchers_function_signatures;
@@ -47,6 +55,7 @@ use crate::ir::values::InstMatcherValue;
 use crate::matchers::other::AnyInstMatcher;
 use crate::matchers::Matcher;
 
+pub(crate) mod accessors;
 pub(crate) mod matchers;
 
 #[inline(always)]
@@ -70,6 +79,10 @@ pub fn llvm_ir_functions() -> &'static HashMap<&'static str, StandardFunction> {
         register_debug_inst_matchers_functions(&mut map);
         register_operand_bundle_inst_matchers_functions(&mut map);
         register_get_element_ptr_inst_matchers_functions(&mut map);
+        register_context_matchers_functions(&mut map);
+        register_pattern_matchers_functions(&mut map);
+        register_function_matchers_functions(&mut map);
+        register_accessors_functions(&mut map);
         map
     })
 }
@@ -93,6 +106,10 @@ pub fn llvm_ir_function_signatures() -> HashMap<&'static str, Signature> {
     register_debug_inst_matchers_function_signatures(&mut map);
     register_operand_bundle_inst_matchers_function_signatures(&mut map);
     register_get_element_ptr_inst_matchers_function_signatures(&mut map);
+    register_context_matchers_function_signatures(&mut map);
+    register_pattern_matchers_function_signatures(&mut map);
+    register_function_matchers_function_signatures(&mut map);
+    register_accessors_function_signatures(&mut map);
 
     map
 }
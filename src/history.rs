@@ -0,0 +1,148 @@
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+const HISTORY_MAX_ENTRIES: usize = 1000;
+
+/// Line editor history that is persisted to the user config directory
+#[derive(Default)]
+pub struct History {
+    path: Option<PathBuf>,
+    entries: Vec<String>,
+}
+
+impl History {
+    /// Load the history file from the user config directory, or empty history if it not exists
+    pub fn load() -> Self {
+        let path = history_file_path();
+        let entries = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|content| content.lines().map(unescape_entry).collect())
+            .unwrap_or_default();
+
+        let mut history = History { path, entries };
+        history.truncate();
+        history
+    }
+
+    /// Add entry to the history and append it to the history file
+    pub fn push(&mut self, entry: &str) {
+        let entry = entry.trim();
+        if entry.is_empty() || self.entries.last().is_some_and(|last| last == entry) {
+            return;
+        }
+
+        self.entries.push(entry.to_string());
+        let truncated = self.truncate();
+
+        let Some(path) = self.path.clone() else {
+            return;
+        };
+
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        // Rewrite the file when it reaches the limit, otherwise append only the new entry
+        if truncated {
+            let content: Vec<String> = self.entries.iter().map(|e| escape_entry(e)).collect();
+            let _ = fs::write(&path, content.join("\n") + "\n");
+        } else if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&path) {
+            let _ = writeln!(file, "{}", escape_entry(entry));
+        }
+    }
+
+    /// Search the history from the most recent entry for entries that contains the pattern
+    pub fn search_reverse<'a>(&'a self, pattern: &'a str) -> impl Iterator<Item = &'a String> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(move |entry| entry.contains(pattern))
+    }
+
+    fn truncate(&mut self) -> bool {
+        if self.entries.len() <= HISTORY_MAX_ENTRIES {
+            return false;
+        }
+        let extra = self.entries.len() - HISTORY_MAX_ENTRIES;
+        self.entries.drain(..extra);
+        true
+    }
+}
+
+/// Return `llql/history` path inside the user config directory
+fn history_file_path() -> Option<PathBuf> {
+    let config_dir = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+    };
+    config_dir.map(|dir| dir.join("llql").join("history"))
+}
+
+/// Escape new lines so multi-line queries are stored in a single line
+fn escape_entry(entry: &str) -> String {
+    entry.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape_entry(line: &str) -> String {
+    let mut entry = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            entry.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => entry.push('\n'),
+            Some(next) => entry.push(next),
+            None => entry.push('\\'),
+        }
+    }
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_entry_round_trip() {
+        let entries = [
+            "SELECT * FROM instructions",
+            "SELECT *\nFROM instructions\nLIMIT 1",
+            "SELECT 'a\\nb'",
+            "SELECT '\\'\n",
+            "\\",
+        ];
+
+        for entry in entries {
+            let escaped = escape_entry(entry);
+            assert!(!escaped.contains('\n'));
+            assert_eq!(unescape_entry(&escaped), entry);
+        }
+    }
+
+    #[test]
+    fn test_history_truncate() {
+        let mut history = History::default();
+        for i in 0..HISTORY_MAX_ENTRIES {
+            history.push(&format!("SELECT {i}"));
+        }
+        assert!(!history.truncate());
+        assert_eq!(history.entries.len(), HISTORY_MAX_ENTRIES);
+
+        history.push("SELECT last");
+        assert_eq!(history.entries.len(), HISTORY_MAX_ENTRIES);
+        assert_eq!(history.entries.first().unwrap(), "SELECT 1");
+        assert_eq!(
+            history.search_reverse("SELECT").next().unwrap(),
+            "SELECT last"
+        );
+    }
+}
@@ -0,0 +1,421 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::ir::module_parser::is_llvm_module_path;
+use crate::ir::module_parser::STDIN_PATH;
+use crate::matchers::matchers_helper::is_glob_match;
+
+/// Resolve the input paths to LLVM IR or Bitcode files
+///
+/// Each input can be a file, a directory that is searched recursively, or `@file`
+/// response file that contains one input per line. Files found inside directories
+/// are filtered by the include and exclude glob patterns
+pub fn resolve_input_files(
+    inputs: &[String],
+    include_globs: &[String],
+    exclude_globs: &[String],
+) -> Result<Vec<String>, String> {
+    let mut files: Vec<String> = vec![];
+    for input in inputs {
+        resolve_input(input, include_globs, exclude_globs, &mut files)?;
+    }
+
+    let mut seen_files = HashSet::new();
+    files.retain(|file| seen_files.insert(file.clone()));
+    Ok(files)
+}
+
+fn resolve_input(
+    input: &str,
+    include_globs: &[String],
+    exclude_globs: &[String],
+    files: &mut Vec<String>,
+) -> Result<(), String> {
+    if let Some(response_file) = input.strip_prefix('@') {
+        for line in read_response_file(response_file)? {
+            if line.starts_with('@') {
+                return Err(format!("Nested response file {line} is not supported"));
+            }
+            resolve_input(&line, include_globs, exclude_globs, files)?;
+        }
+        return Ok(());
+    }
+
+    let path = Path::new(input);
+    if !path.is_dir() {
+        files.push(input.to_string());
+        return Ok(());
+    }
+
+    let mut directory_files: Vec<String> = vec![];
+    collect_directory_files(path, &mut directory_files)?;
+    directory_files.sort();
+    directory_files.retain(|file| is_included_file(file, include_globs, exclude_globs));
+    files.append(&mut directory_files);
+    Ok(())
+}
+
+/// Return the non empty lines of the response file, lines that start with `#` are comments
+fn read_response_file(path: &str) -> Result<Vec<String>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|error| format!("Can't read response file {path}: {error}"))?;
+
+    Ok(content
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.to_string())
+        .collect())
+}
+
+fn collect_directory_files(directory: &Path, files: &mut Vec<String>) -> Result<(), String> {
+    let entries = fs::read_dir(directory)
+        .map_err(|error| format!("Can't read directory {}: {error}", directory.display()))?;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_directory_files(&path, files)?;
+            continue;
+        }
+
+        if is_llvm_file_extension(&path) {
+            files.push(path.to_string_lossy().to_string());
+        }
+    }
+
+    Ok(())
+}
+
+fn is_llvm_file_extension(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "ll" || extension == "bc")
+}
+
+fn is_included_file(file: &str, include_globs: &[String], exclude_globs: &[String]) -> bool {
+    let is_included = include_globs.is_empty()
+        || include_globs
+            .iter()
+            .any(|pattern| is_glob_match(pattern, file));
+
+    is_included
+        && !exclude_globs
+            .iter()
+            .any(|pattern| is_glob_match(pattern, file))
+}
+
+/// Return the Bitcode files produced next to the outputs of `compile_commands.json` entries
+///
+/// The output of each entry is taken from the `output` field or the `-o` argument, relative
+/// to the entry directory, and the file with the same name and `.bc` extension is used
+pub fn read_compile_commands_bitcode_files(path: &str) -> Result<Vec<String>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|error| format!("Can't read compilation database {path}: {error}"))?;
+
+    let entries: Vec<serde_json::Value> = serde_json::from_str(&content)
+        .map_err(|error| format!("Invalid compilation database {path}: {error}"))?;
+
+    let mut files: Vec<String> = vec![];
+    for entry in entries.iter() {
+        let Some(output) = compile_command_output(entry) else {
+            continue;
+        };
+
+        let directory = entry["directory"].as_str().unwrap_or_default();
+        let output = Path::new(directory).join(output);
+        let bitcode_file = if is_llvm_file_extension(&output) {
+            output
+        } else {
+            output.with_extension("bc")
+        };
+
+        if bitcode_file.exists() {
+            files.push(bitcode_file.to_string_lossy().to_string());
+        }
+    }
+
+    if files.is_empty() {
+        return Err(format!(
+            "No Bitcode outputs found for compilation database {path}"
+        ));
+    }
+
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+fn compile_command_output(entry: &serde_json::Value) -> Option<PathBuf> {
+    if let Some(output) = entry["output"].as_str() {
+        return Some(PathBuf::from(output));
+    }
+
+    let arguments: Vec<String> = if let Some(arguments) = entry["arguments"].as_array() {
+        arguments
+            .iter()
+            .filter_map(|argument| argument.as_str())
+            .map(|argument| argument.to_string())
+            .collect()
+    } else {
+        split_command_line(entry["command"].as_str()?)
+    };
+
+    let mut iter = arguments.iter();
+    while let Some(argument) = iter.next() {
+        if argument == "-o" {
+            return iter.next().map(PathBuf::from);
+        }
+
+        // The joined `-o<file>` form, other options like `-objcmt-*` has no file extension
+        if let Some(output) = argument.strip_prefix("-o") {
+            let output = PathBuf::from(output);
+            if output.extension().is_some() {
+                return Some(output);
+            }
+        }
+    }
+
+    None
+}
+
+/// Split the `command` of compilation database entry into arguments like a shell,
+/// whitespace inside single or double quotes and escaped with `\` is part of the argument
+fn split_command_line(command: &str) -> Vec<String> {
+    let mut arguments: Vec<String> = vec![];
+    let mut argument = String::new();
+    let mut has_argument = false;
+    let mut quote: Option<char> = None;
+    let mut chars = command.chars();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some('\''), '\'') => quote = None,
+            (Some('\''), _) => argument.push(c),
+            (Some('"'), '"') => quote = None,
+            (Some('"'), '\\') => match chars.next() {
+                Some(next) if matches!(next, '"' | '\\' | '$' | '`') => argument.push(next),
+                Some(next) => {
+                    argument.push('\\');
+                    argument.push(next);
+                }
+                None => argument.push('\\'),
+            },
+            (Some(_), _) => argument.push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                has_argument = true;
+            }
+            (None, '\\') => {
+                if let Some(next) = chars.next() {
+                    argument.push(next);
+                }
+                has_argument = true;
+            }
+            (None, _) if c.is_whitespace() => {
+                if has_argument {
+                    arguments.push(std::mem::take(&mut argument));
+                    has_argument = false;
+                }
+            }
+            (None, _) => {
+                argument.push(c);
+                has_argument = true;
+            }
+        }
+    }
+
+    if has_argument {
+        arguments.push(argument);
+    }
+    arguments
+}
+
+/// Validate that the files exist and can be loaded as LLVM modules
+pub fn validate_files_paths(files: &[String]) -> Result<(), String> {
+    for file in files {
+        if file != STDIN_PATH && !Path::new(file).exists() {
+            return Err(format!("File ${file} is not exists"));
+        }
+
+        if is_llvm_module_path(file) {
+            continue;
+        }
+
+        return Err(format!(
+            "File ${file} must end with LL, BC, A or O extension"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("llql-inputs-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn create_files(dir: &Path, files: &[&str]) -> Vec<String> {
+        files
+            .iter()
+            .map(|file| {
+                let path = dir.join(file);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(&path, "").unwrap();
+                path.to_string_lossy().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_resolve_input_files() {
+        let dir = temp_dir("resolve");
+        let files = create_files(
+            &dir,
+            &[
+                "main.ll",
+                "lib/util.bc",
+                "lib/notes.txt",
+                "lib/test/util_test.ll",
+            ],
+        );
+        let dir_path = dir.to_string_lossy().to_string();
+
+        let resolved = resolve_input_files(std::slice::from_ref(&dir_path), &[], &[]).unwrap();
+        assert_eq!(
+            resolved,
+            vec![files[3].clone(), files[1].clone(), files[0].clone()]
+        );
+
+        let include = vec!["*/lib/*".to_string()];
+        let exclude = vec!["*_test.ll".to_string()];
+        let resolved =
+            resolve_input_files(std::slice::from_ref(&dir_path), &include, &exclude).unwrap();
+        assert_eq!(resolved, vec![files[1].clone()]);
+
+        // Explicit files are not filtered by the globs and duplicates are removed
+        let inputs = vec![files[0].clone(), dir_path.clone()];
+        let resolved = resolve_input_files(&inputs, &include, &[]).unwrap();
+        assert_eq!(
+            resolved,
+            vec![files[0].clone(), files[3].clone(), files[1].clone()]
+        );
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_resolve_response_files() {
+        let dir = temp_dir("response");
+        let files = create_files(&dir, &["main.ll", "lib/util.bc"]);
+        let lib_dir = dir.join("lib").to_string_lossy().to_string();
+
+        let response_file = dir.join("inputs.rsp");
+        let content = format!("# Inputs\n\n  {}  \n{lib_dir}\n", files[0]);
+        fs::write(&response_file, content).unwrap();
+
+        let input = format!("@{}", response_file.display());
+        let resolved = resolve_input_files(std::slice::from_ref(&input), &[], &[]).unwrap();
+        assert_eq!(resolved, files);
+
+        let nested_file = dir.join("nested.rsp");
+        fs::write(&nested_file, format!("{input}\n")).unwrap();
+        let nested = format!("@{}", nested_file.display());
+        let error = resolve_input_files(&[nested], &[], &[]).unwrap_err();
+        assert_eq!(
+            error,
+            format!("Nested response file {input} is not supported")
+        );
+
+        let missing = format!("@{}", dir.join("missing.rsp").display());
+        assert!(resolve_input_files(&[missing], &[], &[]).is_err());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_read_compile_commands_bitcode_files() {
+        let dir = temp_dir("compile-commands");
+        let files = create_files(
+            &dir,
+            &[
+                "build/main.bc",
+                "build/my dir/util.bc",
+                "build/lib.bc",
+                "build/joined.bc",
+            ],
+        );
+        let build = dir.join("build");
+        let database = serde_json::json!([
+            {
+                "directory": build,
+                "arguments": ["clang", "-c", "-objcmt-migrate-all", "-o", "main.o", "../main.c"],
+                "file": "../main.c"
+            },
+            {
+                "directory": build,
+                "command": "clang -c -DNAME=\"a b\" -o 'my dir/util.o' ../util.c",
+                "file": "../util.c"
+            },
+            {
+                "directory": build,
+                "command": "clang -c -o lib\\ missing.o ../missing.c",
+                "file": "../missing.c"
+            },
+            {
+                "directory": build,
+                "command": "clang -c -objcmt-migrate-all ../objc.m",
+                "file": "../objc.m"
+            },
+            {
+                "directory": build,
+                "command": "clang -c -ojoined.o ../joined.c",
+                "file": "../joined.c"
+            },
+            {
+                "directory": build,
+                "command": "clang -c ../lib.c",
+                "output": "lib.o",
+                "file": "../lib.c"
+            }
+        ]);
+        let database_path = dir.join("compile_commands.json");
+        fs::write(&database_path, database.to_string()).unwrap();
+
+        let bitcode_files =
+            read_compile_commands_bitcode_files(&database_path.to_string_lossy()).unwrap();
+        let mut expected = files.clone();
+        expected.sort();
+        assert_eq!(bitcode_files, expected);
+
+        fs::write(&database_path, "[]").unwrap();
+        assert!(read_compile_commands_bitcode_files(&database_path.to_string_lossy()).is_err());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_split_command_line() {
+        let arguments = split_command_line(
+            r#"clang -DNAME="a \"b\"" -I'my include' -o out\ dir/main.o '' main.c"#,
+        );
+        assert_eq!(
+            arguments,
+            vec![
+                "clang",
+                "-DNAME=a \"b\"",
+                "-Imy include",
+                "-o",
+                "out dir/main.o",
+                "",
+                "main.c"
+            ]
+        );
+    }
+}
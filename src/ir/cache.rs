@@ -0,0 +1,275 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use super::module_index::ModuleIndex;
+
+/// Cache entries written by other LLQL versions are ignored because the indexes may differ
+const CACHE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// On-disk cache of the module indexes, each input file has one entry that is keyed by the
+/// canonical file path and validated by its size, modification time and content hash
+pub struct ModuleCache {
+    dir: PathBuf,
+}
+
+/// Size and modification time of the input file when the entry was written
+#[derive(PartialEq)]
+struct FileStamp {
+    size: u64,
+    modified_nanos: u64,
+}
+
+impl ModuleCache {
+    /// Open the cache inside the user cache directory, or `None` if it can't be found
+    pub fn open() -> Option<Self> {
+        cache_dir_path().map(Self::with_dir)
+    }
+
+    /// Open the cache inside a custom directory
+    pub fn with_dir(dir: PathBuf) -> Self {
+        ModuleCache { dir }
+    }
+
+    /// Return the names and indexes of the modules in the input file if the entry is still valid
+    pub fn lookup(&self, path: &str) -> Option<Vec<(String, ModuleIndex)>> {
+        let stamp = file_stamp(path)?;
+        let canonical_path = canonical_path(path);
+        let content = fs::read_to_string(self.entry_path(&canonical_path)).ok()?;
+        let entry: JsonValue = serde_json::from_str(&content).ok()?;
+
+        if entry.get("version")?.as_str()? != CACHE_VERSION
+            || entry.get("path")?.as_str()? != canonical_path
+            || entry.get("size")?.as_u64()? != stamp.size
+        {
+            return None;
+        }
+
+        let modules = read_entry_modules(&entry)?;
+
+        // File may be touched or copied without changes, so fall back to compare the content
+        if entry.get("modified")?.as_u64()? != stamp.modified_nanos {
+            let hash = file_content_hash(path)?;
+            if entry.get("hash")?.as_str()? != format!("{hash:016x}") {
+                return None;
+            }
+
+            // Update the modification time with the hash that is already computed
+            let indexes: Vec<(String, &ModuleIndex)> =
+                modules.iter().map(|(n, i)| (n.to_string(), i)).collect();
+            self.write_entry(&canonical_path, &stamp, hash, &indexes);
+        }
+
+        Some(modules)
+    }
+
+    /// Write the indexes of the modules in the input file, failing to write is ignored
+    /// because the cache only makes the next runs faster
+    pub fn store(&self, path: &str, modules: &[(String, &ModuleIndex)]) {
+        let Some(stamp) = file_stamp(path) else {
+            return;
+        };
+
+        let Some(hash) = file_content_hash(path) else {
+            return;
+        };
+
+        self.write_entry(&canonical_path(path), &stamp, hash, modules);
+    }
+
+    fn write_entry(
+        &self,
+        canonical_path: &str,
+        stamp: &FileStamp,
+        hash: u64,
+        modules: &[(String, &ModuleIndex)],
+    ) {
+        let modules: Vec<JsonValue> = modules
+            .iter()
+            .map(|(name, index)| json!({ "name": name, "index": index.to_json() }))
+            .collect();
+
+        let entry = json!({
+            "version": CACHE_VERSION,
+            "path": canonical_path,
+            "size": stamp.size,
+            "modified": stamp.modified_nanos,
+            "hash": format!("{hash:016x}"),
+            "modules": modules,
+        });
+
+        if fs::create_dir_all(&self.dir).is_err() {
+            return;
+        }
+
+        // Write to a temporary file first so parallel runs never read half written entry
+        let entry_path = self.entry_path(canonical_path);
+        let temp_path = entry_path.with_extension(format!("{}.tmp", std::process::id()));
+        if fs::write(&temp_path, entry.to_string()).is_ok()
+            && fs::rename(&temp_path, &entry_path).is_err()
+        {
+            let _ = fs::remove_file(&temp_path);
+        }
+    }
+
+    /// Return the entry file path, which is keyed by the canonical path of the input file
+    fn entry_path(&self, canonical_path: &str) -> PathBuf {
+        let key = fnv1a_hash(canonical_path.as_bytes());
+        self.dir.join(format!("{key:016x}.json"))
+    }
+}
+
+/// Return the absolute path without links, so the same file has the same entry for any
+/// relative path it loaded from
+fn canonical_path(path: &str) -> String {
+    fs::canonicalize(path)
+        .map(|path| path.to_string_lossy().to_string())
+        .unwrap_or_else(|_| path.to_string())
+}
+
+fn read_entry_modules(entry: &JsonValue) -> Option<Vec<(String, ModuleIndex)>> {
+    let mut modules = vec![];
+    for module in entry.get("modules")?.as_array()? {
+        let name = module.get("name")?.as_str()?.to_string();
+        let index = ModuleIndex::from_json(module.get("index")?)?;
+        modules.push((name, index));
+    }
+    Some(modules)
+}
+
+fn file_stamp(path: &str) -> Option<FileStamp> {
+    let metadata = fs::metadata(Path::new(path)).ok()?;
+    let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some(FileStamp {
+        size: metadata.len(),
+        modified_nanos: modified.as_nanos() as u64,
+    })
+}
+
+fn file_content_hash(path: &str) -> Option<u64> {
+    fs::read(path).ok().map(|content| fnv1a_hash(&content))
+}
+
+/// FNV-1a hash, used instead of the std hasher because it must be the same between runs
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Return `llql` path inside the user cache directory
+fn cache_dir_path() -> Option<PathBuf> {
+    let cache_dir = if cfg!(windows) {
+        std::env::var_os("LOCALAPPDATA").map(PathBuf::from)
+    } else {
+        std::env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+    };
+    cache_dir.map(|dir| dir.join("llql"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/sample.ll");
+
+    #[test]
+    fn test_lookup_with_other_path_of_same_file() {
+        let cache_dir = std::env::temp_dir().join(format!("llql-cache-{}", std::process::id()));
+        let cache = ModuleCache::with_dir(cache_dir.clone());
+
+        let index = ModuleIndex::default();
+        cache.store(
+            "tests/fixtures/sample.ll",
+            &[("sample".to_string(), &index)],
+        );
+
+        let modules = cache.lookup(SAMPLE_PATH).unwrap();
+        assert_eq!(modules, vec![("sample".to_string(), index)]);
+        assert!(cache.lookup("tests/fixtures/math.ll").is_none());
+
+        let _ = fs::remove_dir_all(cache_dir);
+    }
+
+    fn read_entry(cache: &ModuleCache, path: &str) -> JsonValue {
+        let entry_path = cache.entry_path(&canonical_path(path));
+        serde_json::from_str(&fs::read_to_string(entry_path).unwrap()).unwrap()
+    }
+
+    fn set_modified(path: &str, seconds: u64) {
+        let modified = UNIX_EPOCH + std::time::Duration::from_secs(seconds);
+        let file = fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(modified).unwrap();
+    }
+
+    #[test]
+    fn test_lookup_invalidation() {
+        let cache_dir =
+            std::env::temp_dir().join(format!("llql-cache-invalidation-{}", std::process::id()));
+        let cache = ModuleCache::with_dir(cache_dir.clone());
+        fs::create_dir_all(&cache_dir).unwrap();
+
+        let path = cache_dir.join("sample.ll").to_string_lossy().to_string();
+        let content = fs::read_to_string(SAMPLE_PATH).unwrap();
+        fs::write(&path, &content).unwrap();
+        set_modified(&path, 1_000);
+
+        let index = ModuleIndex::default();
+        let modules = vec![("sample".to_string(), index.clone())];
+        cache.store(&path, &[("sample".to_string(), &index)]);
+        assert_eq!(cache.lookup(&path), Some(modules.clone()));
+
+        // Modification time changed without changing the content updates the entry
+        set_modified(&path, 2_000);
+        assert_eq!(cache.lookup(&path), Some(modules.clone()));
+        let entry = read_entry(&cache, &path);
+        assert_eq!(entry["modified"].as_u64(), Some(2_000_000_000_000));
+        assert_eq!(
+            entry["hash"].as_str().unwrap(),
+            format!("{:016x}", fnv1a_hash(content.as_bytes()))
+        );
+
+        // Same size with different content and modification time
+        fs::write(&path, content.replacen("define", "declar", 1)).unwrap();
+        set_modified(&path, 3_000);
+        assert_eq!(cache.lookup(&path), None);
+
+        // Different size
+        fs::write(&path, &content).unwrap();
+        cache.store(&path, &[("sample".to_string(), &index)]);
+        assert_eq!(cache.lookup(&path), Some(modules.clone()));
+        fs::write(&path, format!("{content}\n")).unwrap();
+        assert_eq!(cache.lookup(&path), None);
+
+        let _ = fs::remove_dir_all(cache_dir);
+    }
+
+    #[test]
+    fn test_lookup_version_mismatch() {
+        let cache_dir =
+            std::env::temp_dir().join(format!("llql-cache-version-{}", std::process::id()));
+        let cache = ModuleCache::with_dir(cache_dir.clone());
+
+        let index = ModuleIndex::default();
+        cache.store(SAMPLE_PATH, &[("sample".to_string(), &index)]);
+        assert!(cache.lookup(SAMPLE_PATH).is_some());
+
+        let mut entry = read_entry(&cache, SAMPLE_PATH);
+        assert_eq!(entry["version"].as_str(), Some(CACHE_VERSION));
+        entry["version"] = json!("0.0.0");
+        let entry_path = cache.entry_path(&canonical_path(SAMPLE_PATH));
+        fs::write(entry_path, entry.to_string()).unwrap();
+        assert!(cache.lookup(SAMPLE_PATH).is_none());
+
+        let _ = fs::remove_dir_all(cache_dir);
+    }
+}
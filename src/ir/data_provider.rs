@@ -1,101 +1,495 @@
-use std::sync::LazyLock;
+use std::sync::Arc;
 
 use gitql_core::object::Row;
+use gitql_core::values::integer::IntValue;
 use gitql_core::values::null::NullValue;
 use gitql_core::values::text::TextValue;
 use gitql_core::values::Value;
 use gitql_engine::data_provider::DataProvider;
-use inkwell::context::Context;
 use inkwell::module::Module;
+use inkwell::types::AsTypeRef;
 use inkwell::values::AsValueRef;
 
+use super::filters::ProviderFilters;
+use super::filters::SharedProviderFilters;
+use super::module_index::ModuleIndex;
+use super::module_store::ModuleStore;
+use super::parallel::default_jobs;
+use super::parallel::parallel_map;
+use super::values::LLVMFunctionValue;
+use super::values::LLVMInstPosition;
 use super::values::LLVMInstValue;
+use super::values::LLVMTypeValue;
 
-pub(crate) static mut LLVM_CONTEXT: LazyLock<Context> = LazyLock::new(Context::create);
-pub(crate) static mut LLVM_MODULES: Vec<Module> = Vec::new();
-
+#[derive(Clone)]
 pub struct LLVMIRDataProvider {
-    paths: Vec<String>,
+    store: Arc<ModuleStore>,
+    jobs: usize,
+    filters: SharedProviderFilters,
 }
 
 impl LLVMIRDataProvider {
-    pub fn new(paths: Vec<String>) -> Self {
-        Self { paths }
+    pub fn new(store: Arc<ModuleStore>) -> Self {
+        Self::with_jobs(store, default_jobs())
+    }
+
+    /// Create provider that select rows from up to `jobs` modules in parallel
+    pub fn with_jobs(store: Arc<ModuleStore>, jobs: usize) -> Self {
+        Self {
+            store,
+            jobs,
+            filters: SharedProviderFilters::default(),
+        }
+    }
+
+    /// Return the filters handle that is used to skip functions and blocks before the query run
+    pub fn filters(&self) -> SharedProviderFilters {
+        self.filters.clone()
+    }
+
+    /// Return the module at position with its index and the current filters
+    fn module_source<'a>(
+        &'a self,
+        position: usize,
+        filters: &'a ProviderFilters,
+    ) -> ModuleSource<'a> {
+        ModuleSource {
+            store: &self.store,
+            module: self.store.module(position),
+            module_index: self.store.module_index(position),
+            module_name: self.store.module_name(position),
+            module_position: position,
+            filters,
+        }
     }
 }
 
+/// Provider shared with the workers that select rows from the modules in parallel
+struct ParallelProvider<'a>(&'a LLVMIRDataProvider);
+
+// SAFETY: The provider is not `Sync` because the store holds LLVM contexts and modules. Workers
+// only read the store through `module_source`:
+// - Each module has its own context and each worker selects rows from different modules, so a
+//   context is never used by two threads at the same time
+// - Modules are parsed by `ensure_parsed` before the workers start, so the `OnceLock` of each
+//   module is only read and never set while the workers run
+// - The store `Arc` is cloned into the values with atomic reference counting, and the provider
+//   keeps its own `Arc` so the store is never dropped by a worker
+// - `parallel_map` joins all workers before it returns, so no worker outlives the borrow
+unsafe impl Sync for ParallelProvider<'_> {}
+
+impl<'a> ParallelProvider<'a> {
+    fn module_source(&self, position: usize, filters: &'a ProviderFilters) -> ModuleSource<'a> {
+        self.0.module_source(position, filters)
+    }
+}
+
+/// Rows selected from one module
+struct ModuleRows(Result<Vec<Row>, String>);
+
+// SAFETY: Values of the rows hold raw LLVM pointers and `Arc` clones of the store, so they are
+// not `Send`. The rows point only into the module that the worker selected them from, and they
+// are moved to the caller thread after the worker finished and never used by the worker again,
+// so the module context is still used by one thread at a time
+unsafe impl Send for ModuleRows {}
+
 impl DataProvider for LLVMIRDataProvider {
     fn provide(&self, table: &str, selected_columns: &[String]) -> Result<Vec<Row>, String> {
-        let mut rows: Vec<Row> = vec![];
+        let filters = self.filters.read().map(|f| f.clone()).unwrap_or_default();
+
+        // Tables that are computed from the modules indexes can be selected without parsing,
+        // and other tables parse only the modules that has functions accepted by the filters
+        let needs_modules = table_needs_modules(table, selected_columns);
+        let modules_positions: Vec<usize> = (0..self.store.len())
+            .filter(|position| {
+                !needs_modules || filters.accept_module(self.store.module_index(*position))
+            })
+            .collect();
 
-        for (path_index, path) in self.paths.iter().enumerate() {
-            let mut selected_rows =
-                select_llvm_ir_objects(path, path_index, table, selected_columns)?;
-            rows.append(&mut selected_rows);
+        // The first rows are taken in the modules order, so modules are walked one by one
+        // and the walk stops as soon as the limit is reached before parsing the next module
+        if filters.rows_limit.is_some() {
+            let mut batch = RowsBatch::new(filters.rows_limit);
+            for position in modules_positions {
+                if batch.is_full() {
+                    break;
+                }
+                if needs_modules {
+                    self.store.ensure_parsed(&[position], 1)?;
+                }
+                let source = self.module_source(position, &filters);
+                select_llvm_ir_objects(&source, table, selected_columns, &mut batch)?;
+            }
+            return Ok(batch.rows);
+        }
+
+        if needs_modules {
+            self.store.ensure_parsed(&modules_positions, self.jobs)?;
+        }
+
+        let provider = ParallelProvider(self);
+        let modules_rows = parallel_map(&modules_positions, self.jobs, |position| {
+            let source = provider.module_source(*position, &filters);
+            let mut batch = RowsBatch::new(None);
+            let result = select_llvm_ir_objects(&source, table, selected_columns, &mut batch);
+            ModuleRows(result.map(|_| batch.rows))
+        });
+
+        let modules_rows = modules_rows
+            .into_iter()
+            .map(|ModuleRows(module_rows)| module_rows)
+            .collect::<Result<Vec<Vec<Row>>, String>>()?;
+
+        // Rows are merged in the modules order to be the same for any number of jobs
+        let rows_count = modules_rows
+            .iter()
+            .map(|module_rows| module_rows.len())
+            .sum();
+        let mut rows: Vec<Row> = Vec::with_capacity(rows_count);
+        for mut module_rows in modules_rows {
+            rows.append(&mut module_rows);
         }
 
         Ok(rows)
     }
 }
 
+/// Return true if the selected columns of the table has LLVM values that need the parsed module
+fn table_needs_modules(table: &str, selected_columns: &[String]) -> bool {
+    match table {
+        "instructions" => true,
+        "functions" => selected_columns.iter().any(|column| column == "function"),
+        _ => false,
+    }
+}
+
+/// Module with its name, index and position in the store that rows are selected from
+struct ModuleSource<'a> {
+    store: &'a Arc<ModuleStore>,
+    module: Option<&'a Module<'static>>,
+    module_index: &'a ModuleIndex,
+    module_name: &'a str,
+    module_position: usize,
+    filters: &'a ProviderFilters,
+}
+
+/// Selected rows with an optional limit, the modules walk stops once the batch is full
+///
+/// Rows can't be streamed because `DataProvider::provide` returns all of them at once,
+/// and names are cloned for each row because `TextValue` owns its text, so memory is
+/// bounded only by the filters pushed down to the provider and the query limit
+struct RowsBatch {
+    rows: Vec<Row>,
+    limit: Option<usize>,
+}
+
+impl RowsBatch {
+    fn new(limit: Option<usize>) -> Self {
+        RowsBatch {
+            rows: vec![],
+            limit,
+        }
+    }
+
+    /// Reserve space for the expected rows once instead of growing the rows many times
+    fn reserve(&mut self, additional: usize) {
+        let remaining = self.limit.map_or(additional, |limit| {
+            additional.min(limit.saturating_sub(self.rows.len()))
+        });
+        self.rows.reserve(remaining);
+    }
+
+    fn push(&mut self, row: Row) {
+        self.rows.push(row);
+    }
+
+    fn is_full(&self) -> bool {
+        self.limit.is_some_and(|limit| self.rows.len() >= limit)
+    }
+}
+
 fn select_llvm_ir_objects(
-    path: &str,
-    path_index: usize,
+    source: &ModuleSource,
     table: &str,
     selected_columns: &[String],
-) -> Result<Vec<Row>, String> {
-    let rows = match table {
-        "instructions" => select_llvm_ir_instructions(path, path_index, selected_columns)?,
-        _ => vec![Row { values: vec![] }],
-    };
-    Ok(rows)
+    batch: &mut RowsBatch,
+) -> Result<(), String> {
+    match table {
+        "instructions" => select_llvm_ir_instructions(source, selected_columns, batch),
+        "functions" => select_llvm_ir_functions(source, selected_columns, batch),
+        "call_graph" => select_llvm_ir_call_graph(source, selected_columns, batch),
+        "opcodes" => select_llvm_ir_opcodes(source, selected_columns, batch),
+        _ => {
+            batch.push(Row { values: vec![] });
+            Ok(())
+        }
+    }
 }
 
 fn select_llvm_ir_instructions(
-    path: &str,
-    path_index: usize,
+    source: &ModuleSource,
     selected_columns: &[String],
-) -> Result<Vec<Row>, String> {
-    let mut rows: Vec<Row> = vec![];
+    batch: &mut RowsBatch,
+) -> Result<(), String> {
+    let Some(module) = source.module else {
+        return Err(format!("Module {} is not parsed", source.module_name));
+    };
+
     let row_width = selected_columns.len();
 
-    unsafe {
-        for function in LLVM_MODULES[path_index].get_functions() {
-            let function_name = function.get_name().to_str().unwrap().to_string();
-            for basic_block in function.get_basic_block_iter() {
-                let basic_block_name = basic_block.get_name().to_str().unwrap().to_string();
-                for inst in basic_block.get_instructions() {
-                    let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(row_width);
-                    for field_name in selected_columns {
-                        if field_name == "function_name" {
-                            values.push(Box::new(TextValue::new(function_name.clone())));
-                            continue;
-                        }
-
-                        if field_name == "basic_block_name" {
-                            values.push(Box::new(TextValue::new(basic_block_name.clone())));
-                            continue;
-                        }
-
-                        if field_name == "instruction" {
-                            values.push(Box::new(LLVMInstValue::new(inst.as_value_ref())));
-                            continue;
-                        }
-
-                        if field_name == "file_name" {
-                            values.push(Box::new(TextValue::new(path.to_string())));
-                            continue;
-                        }
-
-                        values.push(Box::new(NullValue));
+    // The number of rows is known from the index unless some basic blocks are skipped
+    if source.filters.basic_block_names.is_none() {
+        let rows_count = source
+            .module_index
+            .functions
+            .iter()
+            .filter(|function| source.filters.accept_function(&function.name))
+            .map(|function| function.instructions_count)
+            .sum();
+        batch.reserve(rows_count);
+    }
+
+    for (function_index, function) in module.get_functions().enumerate() {
+        if batch.is_full() {
+            return Ok(());
+        }
+
+        let function_name = function.get_name().to_str().unwrap().to_string();
+        if !source.filters.accept_function(&function_name) {
+            continue;
+        }
+
+        for (block_index, basic_block) in function.get_basic_block_iter().enumerate() {
+            let basic_block_name = basic_block.get_name().to_str().unwrap().to_string();
+            if !source.filters.accept_basic_block(&basic_block_name) {
+                continue;
+            }
+
+            for (instruction_index, inst) in basic_block.get_instructions().enumerate() {
+                let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(row_width);
+                for field_name in selected_columns {
+                    if field_name == "function_name" {
+                        values.push(Box::new(TextValue::new(function_name.clone())));
+                        continue;
+                    }
+
+                    if field_name == "basic_block_name" {
+                        values.push(Box::new(TextValue::new(basic_block_name.clone())));
+                        continue;
+                    }
+
+                    if field_name == "instruction" {
+                        let position = LLVMInstPosition {
+                            module_index: source.module_position,
+                            function_index,
+                            block_index,
+                            instruction_index,
+                        };
+                        let llvm_value = inst.as_value_ref();
+                        values.push(Box::new(LLVMInstValue::with_position(
+                            llvm_value,
+                            position,
+                            source.store.clone(),
+                        )));
+                        continue;
+                    }
+
+                    if field_name == "instruction_type" {
+                        let llvm_type = inst.get_type().as_type_ref();
+                        values.push(Box::new(LLVMTypeValue::new(
+                            llvm_type,
+                            source.store.clone(),
+                        )));
+                        continue;
+                    }
+
+                    if field_name == "instruction_index" {
+                        let value = instruction_index as i64;
+                        values.push(Box::new(IntValue { value }));
+                        continue;
+                    }
+
+                    if field_name == "block_index" {
+                        let value = block_index as i64;
+                        values.push(Box::new(IntValue { value }));
+                        continue;
+                    }
+
+                    if field_name == "file_name" {
+                        values.push(Box::new(TextValue::new(source.module_name.to_string())));
+                        continue;
                     }
 
-                    let row = Row { values };
-                    rows.push(row);
+                    values.push(Box::new(NullValue));
+                }
+
+                batch.push(Row { values });
+                if batch.is_full() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn select_llvm_ir_functions(
+    source: &ModuleSource,
+    selected_columns: &[String],
+    batch: &mut RowsBatch,
+) -> Result<(), String> {
+    let row_width = selected_columns.len();
+
+    // Functions in the index are in the same order of the module functions
+    let mut module_functions = source.module.map(|module| module.get_functions());
+    batch.reserve(source.module_index.functions.len());
+    for summary in &source.module_index.functions {
+        if batch.is_full() {
+            break;
+        }
+
+        let function = module_functions
+            .as_mut()
+            .and_then(|functions| functions.next());
+        if !source.filters.accept_function(&summary.name) {
+            continue;
+        }
+
+        let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(row_width);
+        for field_name in selected_columns {
+            if field_name == "function_name" {
+                values.push(Box::new(TextValue::new(summary.name.to_string())));
+                continue;
+            }
+
+            if field_name == "function" {
+                if let Some(function) = function {
+                    let llvm_function = function.as_value_ref();
+                    values.push(Box::new(LLVMFunctionValue::new(
+                        llvm_function,
+                        source.store.clone(),
+                    )));
+                    continue;
                 }
             }
+
+            if field_name == "basic_blocks_count" {
+                let value = summary.basic_blocks_count as i64;
+                values.push(Box::new(IntValue { value }));
+                continue;
+            }
+
+            if field_name == "instructions_count" {
+                let value = summary.instructions_count as i64;
+                values.push(Box::new(IntValue { value }));
+                continue;
+            }
+
+            if field_name == "file_name" {
+                values.push(Box::new(TextValue::new(source.module_name.to_string())));
+                continue;
+            }
+
+            values.push(Box::new(NullValue));
         }
+
+        batch.push(Row { values });
+    }
+
+    Ok(())
+}
+
+fn select_llvm_ir_call_graph(
+    source: &ModuleSource,
+    selected_columns: &[String],
+    batch: &mut RowsBatch,
+) -> Result<(), String> {
+    let row_width = selected_columns.len();
+
+    batch.reserve(source.module_index.call_graph.len());
+    for edge in &source.module_index.call_graph {
+        if batch.is_full() {
+            break;
+        }
+
+        let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(row_width);
+        for field_name in selected_columns {
+            if field_name == "caller_name" {
+                values.push(Box::new(TextValue::new(edge.caller.to_string())));
+                continue;
+            }
+
+            if field_name == "callee_name" {
+                values.push(Box::new(TextValue::new(edge.callee.to_string())));
+                continue;
+            }
+
+            if field_name == "calls_count" {
+                let value = edge.count as i64;
+                values.push(Box::new(IntValue { value }));
+                continue;
+            }
+
+            if field_name == "file_name" {
+                values.push(Box::new(TextValue::new(source.module_name.to_string())));
+                continue;
+            }
+
+            values.push(Box::new(NullValue));
+        }
+
+        batch.push(Row { values });
+    }
+
+    Ok(())
+}
+
+fn select_llvm_ir_opcodes(
+    source: &ModuleSource,
+    selected_columns: &[String],
+    batch: &mut RowsBatch,
+) -> Result<(), String> {
+    let row_width = selected_columns.len();
+
+    batch.reserve(source.module_index.opcodes.len());
+    for opcode in &source.module_index.opcodes {
+        if batch.is_full() {
+            break;
+        }
+
+        if !source.filters.accept_function(&opcode.function) {
+            continue;
+        }
+
+        let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(row_width);
+        for field_name in selected_columns {
+            if field_name == "function_name" {
+                values.push(Box::new(TextValue::new(opcode.function.to_string())));
+                continue;
+            }
+
+            if field_name == "opcode_name" {
+                values.push(Box::new(TextValue::new(opcode.opcode.to_string())));
+                continue;
+            }
+
+            if field_name == "opcode_count" {
+                let value = opcode.count as i64;
+                values.push(Box::new(IntValue { value }));
+                continue;
+            }
+
+            if field_name == "file_name" {
+                values.push(Box::new(TextValue::new(source.module_name.to_string())));
+                continue;
+            }
+
+            values.push(Box::new(NullValue));
+        }
+
+        batch.push(Row { values });
     }
 
-    Ok(rows)
+    Ok(())
 }
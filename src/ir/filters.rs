@@ -0,0 +1,302 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use gitql_ast::expression::ComparisonExpr;
+use gitql_ast::expression::Expr;
+use gitql_ast::expression::InExpr;
+use gitql_ast::expression::LogicalExpr;
+use gitql_ast::expression::StringExpr;
+use gitql_ast::expression::SymbolExpr;
+use gitql_ast::operator::BinaryLogicalOperator;
+use gitql_ast::operator::ComparisonOperator;
+use gitql_ast::query::Query;
+use gitql_ast::query::SelectQuery;
+use gitql_ast::statement::Distinct;
+use gitql_ast::statement::Statement;
+use gitql_core::environment::Environment;
+use gitql_core::object::GitQLObject;
+use gitql_core::object::Row;
+use gitql_engine::data_provider::DataProvider;
+use gitql_engine::engine;
+use gitql_engine::engine::EvaluationResult;
+
+use super::module_index::ModuleIndex;
+
+/// Simple predicates on the cheap columns that the provider applies before creating rows,
+/// `None` means that there is no constraint on this column
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProviderFilters {
+    pub function_names: Option<Vec<String>>,
+    pub basic_block_names: Option<Vec<String>>,
+    /// Maximum number of rows the query can take, so the provider can stop walking the modules
+    pub rows_limit: Option<usize>,
+}
+
+pub type SharedProviderFilters = Arc<RwLock<ProviderFilters>>;
+
+impl ProviderFilters {
+    /// Return true if rows of the function can match the query
+    pub fn accept_function(&self, name: &str) -> bool {
+        self.function_names
+            .as_ref()
+            .is_none_or(|names| names.iter().any(|n| n == name))
+    }
+
+    /// Return true if rows of the basic block can match the query
+    pub fn accept_basic_block(&self, name: &str) -> bool {
+        self.basic_block_names
+            .as_ref()
+            .is_none_or(|names| names.iter().any(|n| n == name))
+    }
+
+    /// Return true if any function of the module index is accepted, so the module may have rows
+    pub fn accept_module(&self, module_index: &ModuleIndex) -> bool {
+        module_index
+            .functions
+            .iter()
+            .any(|function| self.accept_function(&function.name))
+    }
+
+    /// Keep only names that are accepted by both filters
+    fn intersect(self, other: ProviderFilters) -> ProviderFilters {
+        ProviderFilters {
+            function_names: intersect_names(self.function_names, other.function_names),
+            basic_block_names: intersect_names(self.basic_block_names, other.basic_block_names),
+            rows_limit: None,
+        }
+    }
+}
+
+fn intersect_names(lhs: Option<Vec<String>>, rhs: Option<Vec<String>>) -> Option<Vec<String>> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => Some(lhs.into_iter().filter(|n| rhs.contains(n)).collect()),
+        (lhs, None) => lhs,
+        (None, rhs) => rhs,
+    }
+}
+
+/// Extract the filters from the `WHERE` condition and the `LIMIT` of select query
+///
+/// Only conditions that must be true for every selected row are used, which are
+/// `column = 'name'` and `column IN ('a', 'b')` combined with `AND`
+pub fn extract_provider_filters(query: &Query) -> ProviderFilters {
+    let Query::Select(select_query) = query else {
+        return ProviderFilters::default();
+    };
+
+    let mut filters = select_query
+        .statements
+        .get("where")
+        .and_then(|statement| match statement {
+            Statement::Where(where_statement) => {
+                Some(condition_filters(&where_statement.condition))
+            }
+            _ => None,
+        })
+        .unwrap_or_default();
+
+    filters.rows_limit = rows_limit(select_query);
+    filters
+}
+
+/// Return the `LIMIT` count only if the query takes the first rows in the provider order,
+/// which means they are not filtered, grouped, ordered, joined or distinct
+fn rows_limit(query: &SelectQuery) -> Option<usize> {
+    if query
+        .statements
+        .keys()
+        .any(|key| !matches!(*key, "select" | "limit"))
+    {
+        return None;
+    }
+
+    let Statement::Select(select) = query.statements.get("select")? else {
+        return None;
+    };
+
+    if select.table_selections.len() != 1
+        || !select.joins.is_empty()
+        || !matches!(select.distinct, Distinct::None)
+    {
+        return None;
+    }
+
+    match query.statements.get("limit")? {
+        Statement::Limit(limit) => Some(limit.count),
+        _ => None,
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+fn condition_filters(condition: &Box<dyn Expr>) -> ProviderFilters {
+    let condition = condition.as_any();
+
+    if let Some(logical) = condition.downcast_ref::<LogicalExpr>() {
+        if logical.operator == BinaryLogicalOperator::And {
+            return condition_filters(&logical.left).intersect(condition_filters(&logical.right));
+        }
+        return ProviderFilters::default();
+    }
+
+    if let Some(comparison) = condition.downcast_ref::<ComparisonExpr>() {
+        if comparison.operator != ComparisonOperator::Equal {
+            return ProviderFilters::default();
+        }
+
+        // Accept both `column = 'name'` and `'name' = column`
+        let column_and_value = symbol_name(&comparison.left)
+            .zip(string_value(&comparison.right))
+            .or_else(|| symbol_name(&comparison.right).zip(string_value(&comparison.left)));
+
+        if let Some((column, value)) = column_and_value {
+            return column_filters(&column, vec![value]);
+        }
+        return ProviderFilters::default();
+    }
+
+    if let Some(in_expr) = condition.downcast_ref::<InExpr>() {
+        if in_expr.has_not_keyword {
+            return ProviderFilters::default();
+        }
+
+        let Some(column) = symbol_name(&in_expr.argument) else {
+            return ProviderFilters::default();
+        };
+
+        let values: Option<Vec<String>> = in_expr.values.iter().map(string_value).collect();
+        if let Some(values) = values {
+            return column_filters(&column, values);
+        }
+    }
+
+    ProviderFilters::default()
+}
+
+fn column_filters(column: &str, names: Vec<String>) -> ProviderFilters {
+    match column {
+        "function_name" => ProviderFilters {
+            function_names: Some(names),
+            ..Default::default()
+        },
+        "basic_block_name" => ProviderFilters {
+            basic_block_names: Some(names),
+            ..Default::default()
+        },
+        _ => ProviderFilters::default(),
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+fn symbol_name(expr: &Box<dyn Expr>) -> Option<String> {
+    expr.as_any()
+        .downcast_ref::<SymbolExpr>()
+        .map(|symbol| symbol.value.to_string())
+}
+
+#[allow(clippy::borrowed_box)]
+fn string_value(expr: &Box<dyn Expr>) -> Option<String> {
+    expr.as_any()
+        .downcast_ref::<StringExpr>()
+        .map(|string| string.value.to_string())
+}
+
+/// Evaluate the queries one by one, and set the provider filters from each query before it run
+#[allow(clippy::borrowed_box)]
+pub fn evaluate_with_filters(
+    env: &mut Environment,
+    provider: &Box<dyn DataProvider>,
+    filters: &SharedProviderFilters,
+    queries: Vec<Query>,
+) -> Result<Vec<EvaluationResult>, String> {
+    let mut evaluations_results: Vec<EvaluationResult> = vec![];
+    for mut query in queries {
+        if let Ok(mut provider_filters) = filters.write() {
+            *provider_filters = extract_provider_filters(&query);
+        }
+
+        let distinct = take_distinct(&mut query);
+        let evaluation_result = engine::evaluate(env, provider, vec![query]);
+
+        if let Ok(mut provider_filters) = filters.write() {
+            *provider_filters = ProviderFilters::default();
+        }
+
+        let mut evaluation_result = evaluation_result?;
+        for result in evaluation_result.iter_mut() {
+            if let EvaluationResult::SelectedGroups(object) = result {
+                distinct_rows(&distinct, object);
+            }
+        }
+        evaluations_results.append(&mut evaluation_result);
+    }
+    Ok(evaluations_results)
+}
+
+/// Take the distinct of the select statement, so rows are made distinct by `distinct_rows`
+/// which compares the values instead of their printed text like the engine, that would make
+/// different instructions with the same text one row
+fn take_distinct(query: &mut Query) -> Distinct {
+    let Query::Select(select_query) = query else {
+        return Distinct::None;
+    };
+
+    // Rows of `INTO` are written by the engine, and hidden selections are removed from the
+    // result, so distinct on them is left to the engine
+    let is_hidden = |field: &String| {
+        select_query
+            .hidden_selections
+            .values()
+            .any(|hidden| hidden.contains(field))
+    };
+    let select = match select_query.statements.get("select") {
+        Some(Statement::Select(select)) => select,
+        _ => return Distinct::None,
+    };
+    let is_engine_distinct = select_query.statements.contains_key("into")
+        || matches!(&select.distinct, Distinct::DistinctOn(fields) if fields.iter().any(is_hidden));
+    if is_engine_distinct {
+        return Distinct::None;
+    }
+
+    match select_query.statements.get_mut("select") {
+        Some(Statement::Select(select)) => std::mem::replace(&mut select.distinct, Distinct::None),
+        _ => Distinct::None,
+    }
+}
+
+/// Remove the rows that are equal to a previous row in the distinct columns
+fn distinct_rows(distinct: &Distinct, object: &mut GitQLObject) {
+    let columns: Vec<usize> = match distinct {
+        Distinct::None => return,
+        Distinct::DistinctAll => (0..object.titles.len()).collect(),
+        Distinct::DistinctOn(fields) => fields
+            .iter()
+            .filter_map(|field| object.titles.iter().position(|title| title == field))
+            .collect(),
+    };
+
+    for group in object.groups.iter_mut() {
+        // Equal values have the same text, so only rows with the same text are compared
+        let mut rows_by_literals: HashMap<Vec<String>, Vec<usize>> = HashMap::new();
+        let mut rows: Vec<Row> = Vec::with_capacity(group.rows.len());
+        for row in group.rows.drain(..) {
+            let literals = columns
+                .iter()
+                .map(|column| row.values[*column].literal())
+                .collect();
+            let same_literals = rows_by_literals.entry(literals).or_default();
+            let is_duplicate = same_literals.iter().any(|index| {
+                columns
+                    .iter()
+                    .all(|column| rows[*index].values[*column].equals(&row.values[*column]))
+            });
+
+            if !is_duplicate {
+                same_literals.push(rows.len());
+                rows.push(row);
+            }
+        }
+        group.rows = rows;
+    }
+}
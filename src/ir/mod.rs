@@ -1,5 +1,10 @@
+pub mod cache;
 pub mod data_provider;
+pub mod filters;
+pub mod module_index;
 pub mod module_parser;
+pub mod module_store;
+pub mod parallel;
 pub mod schema;
 pub mod types;
 pub mod values;
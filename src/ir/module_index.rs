@@ -0,0 +1,182 @@
+use std::collections::BTreeMap;
+
+use inkwell::llvm_sys::core::LLVMGetCalledValue;
+use inkwell::llvm_sys::core::LLVMGetInstructionOpcode;
+use inkwell::llvm_sys::LLVMOpcode;
+use inkwell::module::Module;
+use inkwell::values::AsValueRef;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::matchers::matchers_helper::llvm_opcode_name;
+use crate::matchers::matchers_helper::llvm_value_name;
+
+/// Summary of one function that doesn't need the module to be parsed
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FunctionSummary {
+    pub name: String,
+    pub basic_blocks_count: usize,
+    pub instructions_count: usize,
+}
+
+/// Number of direct calls from the caller to the callee
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CallEdge {
+    pub caller: String,
+    pub callee: String,
+    pub count: usize,
+}
+
+/// Number of instructions with the opcode inside the function
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OpcodeCount {
+    pub function: String,
+    pub opcode: String,
+    pub count: usize,
+}
+
+/// Precomputed tables of one module, they are stored in the cache so queries on them
+/// can run without parsing the module again
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ModuleIndex {
+    pub functions: Vec<FunctionSummary>,
+    pub globals: Vec<String>,
+    pub call_graph: Vec<CallEdge>,
+    pub opcodes: Vec<OpcodeCount>,
+}
+
+impl ModuleIndex {
+    /// Build the index by walking all functions and instructions of the module
+    pub fn build(module: &Module) -> Self {
+        let mut index = ModuleIndex::default();
+
+        for function in module.get_functions() {
+            let function_name = function.get_name().to_string_lossy().to_string();
+            let mut summary = FunctionSummary {
+                name: function_name.clone(),
+                ..Default::default()
+            };
+
+            let mut callees: BTreeMap<String, usize> = BTreeMap::new();
+            let mut opcodes: BTreeMap<String, usize> = BTreeMap::new();
+            for basic_block in function.get_basic_block_iter() {
+                summary.basic_blocks_count += 1;
+                for instruction in basic_block.get_instructions() {
+                    summary.instructions_count += 1;
+
+                    let value = instruction.as_value_ref();
+                    let opcode = unsafe { LLVMGetInstructionOpcode(value) };
+                    *opcodes.entry(llvm_opcode_name(opcode)).or_default() += 1;
+
+                    if matches!(opcode, LLVMOpcode::LLVMCall | LLVMOpcode::LLVMInvoke) {
+                        let callee = llvm_value_name(&unsafe { LLVMGetCalledValue(value) });
+                        if !callee.is_empty() {
+                            *callees.entry(callee).or_default() += 1;
+                        }
+                    }
+                }
+            }
+
+            for (callee, count) in callees {
+                index.call_graph.push(CallEdge {
+                    caller: function_name.clone(),
+                    callee,
+                    count,
+                });
+            }
+
+            for (opcode, count) in opcodes {
+                index.opcodes.push(OpcodeCount {
+                    function: function_name.clone(),
+                    opcode,
+                    count,
+                });
+            }
+
+            index.functions.push(summary);
+        }
+
+        for global in module.get_globals() {
+            index
+                .globals
+                .push(global.get_name().to_string_lossy().to_string());
+        }
+
+        index
+    }
+
+    /// Write the index as json with each row stored as a compact array
+    pub fn to_json(&self) -> JsonValue {
+        let functions: Vec<JsonValue> = self
+            .functions
+            .iter()
+            .map(|f| json!([f.name, f.basic_blocks_count, f.instructions_count]))
+            .collect();
+
+        let call_graph: Vec<JsonValue> = self
+            .call_graph
+            .iter()
+            .map(|e| json!([e.caller, e.callee, e.count]))
+            .collect();
+
+        let opcodes: Vec<JsonValue> = self
+            .opcodes
+            .iter()
+            .map(|o| json!([o.function, o.opcode, o.count]))
+            .collect();
+
+        json!({
+            "functions": functions,
+            "globals": self.globals,
+            "call_graph": call_graph,
+            "opcodes": opcodes,
+        })
+    }
+
+    /// Read the index from json, or return `None` if it has unexpected format
+    pub fn from_json(json: &JsonValue) -> Option<Self> {
+        let mut index = ModuleIndex::default();
+
+        for function in json.get("functions")?.as_array()? {
+            let (name, basic_blocks_count, instructions_count) = json_triple(function)?;
+            index.functions.push(FunctionSummary {
+                name,
+                basic_blocks_count: basic_blocks_count.as_u64()? as usize,
+                instructions_count: instructions_count.as_u64()? as usize,
+            });
+        }
+
+        for global in json.get("globals")?.as_array()? {
+            index.globals.push(global.as_str()?.to_string());
+        }
+
+        for edge in json.get("call_graph")?.as_array()? {
+            let (caller, callee, count) = json_triple(edge)?;
+            index.call_graph.push(CallEdge {
+                caller,
+                callee: callee.as_str()?.to_string(),
+                count: count.as_u64()? as usize,
+            });
+        }
+
+        for opcode in json.get("opcodes")?.as_array()? {
+            let (function, name, count) = json_triple(opcode)?;
+            index.opcodes.push(OpcodeCount {
+                function,
+                opcode: name.as_str()?.to_string(),
+                count: count.as_u64()? as usize,
+            });
+        }
+
+        Some(index)
+    }
+}
+
+/// Split json array of `[text, value, value]`
+fn json_triple(json: &JsonValue) -> Option<(String, &JsonValue, &JsonValue)> {
+    let array = json.as_array()?;
+    if array.len() != 3 {
+        return None;
+    }
+    Some((array[0].as_str()?.to_string(), &array[1], &array[2]))
+}
@@ -1,35 +1,402 @@
+use std::ffi::CStr;
+use std::io::Read;
+use std::path::Path;
+
+use inkwell::context::Context;
+use inkwell::llvm_sys::core::LLVMCreateMemoryBufferWithMemoryRange;
+use inkwell::llvm_sys::core::LLVMDisposeMemoryBuffer;
+use inkwell::llvm_sys::core::LLVMDisposeMessage;
+use inkwell::llvm_sys::object::LLVMCreateBinary;
+use inkwell::llvm_sys::object::LLVMDisposeBinary;
+use inkwell::llvm_sys::object::LLVMDisposeSectionIterator;
+use inkwell::llvm_sys::object::LLVMGetSectionContents;
+use inkwell::llvm_sys::object::LLVMGetSectionName;
+use inkwell::llvm_sys::object::LLVMGetSectionSize;
+use inkwell::llvm_sys::object::LLVMMoveToNextSection;
+use inkwell::llvm_sys::object::LLVMObjectFileCopySectionIterator;
+use inkwell::llvm_sys::object::LLVMObjectFileIsSectionIteratorAtEnd;
 use inkwell::memory_buffer::MemoryBuffer;
 use inkwell::module::Module;
 
-use super::data_provider::LLVM_CONTEXT;
-use super::data_provider::LLVM_MODULES;
+/// Path used to read the module from the standard input
+pub const STDIN_PATH: &str = "-";
+
+const BITCODE_MAGIC: [u8; 4] = [b'B', b'C', 0xC0, 0xDE];
+const BITCODE_WRAPPER_MAGIC: [u8; 4] = [0xDE, 0xC0, 0x17, 0x0B];
+const ARCHIVE_MAGIC: &[u8] = b"!<arch>\n";
+const ARCHIVE_HEADER_SIZE: usize = 60;
+
+/// Read the inputs as pairs of module name and LLVM IR or Bitcode content
+///
+/// Static archives produce one module for each Bitcode member named as `archive.a(member.o)`,
+/// the standard input module uses the given content or is read from the standard input
+pub fn read_llvm_modules_contents(
+    paths: &[String],
+    stdin_content: Option<&[u8]>,
+) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let mut contents: Vec<(String, Vec<u8>)> = vec![];
+    for path in paths.iter() {
+        if path == STDIN_PATH {
+            let content = match stdin_content {
+                Some(content) => content.to_vec(),
+                None => read_stdin_content()?,
+            };
+            contents.push(("<stdin>".to_string(), content));
+            continue;
+        }
+
+        let content =
+            std::fs::read(path).map_err(|error| format!("Can't read file {path}: {error}"))?;
+
+        if content.starts_with(ARCHIVE_MAGIC) {
+            let contents_count = contents.len();
+            for (member_name, member_content) in read_archive_members(path, &content)? {
+                if let Some(bitcode) = extract_bitcode(&member_content) {
+                    contents.push((format!("{path}({member_name})"), bitcode));
+                }
+            }
+
+            if contents.len() == contents_count {
+                return Err(format!("Archive {path} has no LLVM Bitcode members"));
+            }
+            continue;
+        }
+
+        let content = if path.ends_with(".ll") {
+            content
+        } else {
+            extract_bitcode(&content).ok_or_else(|| format!("File {path} has no LLVM Bitcode"))?
+        };
+
+        contents.push((path.to_string(), content));
+    }
+    Ok(contents)
+}
+
+/// Read the standard input until the end, it can be read only once
+pub fn read_stdin_content() -> Result<Vec<u8>, String> {
+    let mut content: Vec<u8> = vec![];
+    std::io::stdin()
+        .read_to_end(&mut content)
+        .map_err(|error| format!("Can't read the standard input: {error}"))?;
+    Ok(content)
+}
+
+/// Parse the module as Bitcode if it starts with the Bitcode magic, otherwise as textual IR
+pub fn parse_llvm_module<'ctx>(
+    context: &'ctx Context,
+    content: &[u8],
+    name: &str,
+) -> Result<Module<'ctx>, String> {
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(content, name);
+
+    let module_result = if is_bitcode(content) {
+        Module::parse_bitcode_from_buffer(&memory_buffer, context)
+    } else {
+        context.create_module_from_ir(memory_buffer)
+    };
+
+    module_result.map_err(|error| error.to_string())
+}
+
+/// Return true if the content starts with the raw Bitcode magic or the Bitcode wrapper header
+fn is_bitcode(content: &[u8]) -> bool {
+    content.starts_with(&BITCODE_MAGIC) || content.starts_with(&BITCODE_WRAPPER_MAGIC)
+}
+
+/// Return the Bitcode itself, or the content of the `.llvmbc` section of an object file
+fn extract_bitcode(content: &[u8]) -> Option<Vec<u8>> {
+    if is_bitcode(content) {
+        return Some(content.to_vec());
+    }
+
+    extract_object_bitcode_section(content)
+}
+
+/// Return the embedded Bitcode section, `.llvmbc` in ELF and COFF or `__bitcode` in Mach-O
+fn extract_object_bitcode_section(content: &[u8]) -> Option<Vec<u8>> {
+    let mut bitcode: Option<Vec<u8>> = None;
 
-pub fn parse_llvm_modules(paths: &[String]) -> Result<(), String> {
-    #[allow(static_mut_refs)]
     unsafe {
-        LLVM_MODULES.clear();
-        for path in paths.iter() {
-            let module_result = if path.ends_with(".ll") {
-                let memory_buffer_result = MemoryBuffer::create_from_file(path.as_ref());
-                if memory_buffer_result.is_err() {
-                    let error = memory_buffer_result.err().unwrap();
-                    return Err(error.to_string());
+        let memory_buffer = LLVMCreateMemoryBufferWithMemoryRange(
+            content.as_ptr() as *const _,
+            content.len(),
+            c"object".as_ptr(),
+            0,
+        );
+
+        let mut error_message = std::ptr::null_mut();
+        let binary = LLVMCreateBinary(memory_buffer, std::ptr::null_mut(), &mut error_message);
+        if binary.is_null() {
+            if !error_message.is_null() {
+                LLVMDisposeMessage(error_message);
+            }
+            LLVMDisposeMemoryBuffer(memory_buffer);
+            return None;
+        }
+
+        let section = LLVMObjectFileCopySectionIterator(binary);
+        if !section.is_null() {
+            while LLVMObjectFileIsSectionIteratorAtEnd(binary, section) == 0 {
+                let name_ptr = LLVMGetSectionName(section);
+                if !name_ptr.is_null() {
+                    let name = CStr::from_ptr(name_ptr).to_string_lossy();
+                    if name == ".llvmbc" || name == "__bitcode" {
+                        let size = LLVMGetSectionSize(section) as usize;
+                        let data = LLVMGetSectionContents(section) as *const u8;
+                        bitcode = Some(std::slice::from_raw_parts(data, size).to_vec());
+                        break;
+                    }
                 }
+                LLVMMoveToNextSection(section);
+            }
+            LLVMDisposeSectionIterator(section);
+        }
 
-                let memory_buffer = memory_buffer_result.ok().unwrap();
-                LLVM_CONTEXT.create_module_from_ir(memory_buffer)
-            } else {
-                Module::parse_bitcode_from_path(path, &*LLVM_CONTEXT)
-            };
+        LLVMDisposeBinary(binary);
+        LLVMDisposeMemoryBuffer(memory_buffer);
+    }
 
-            if module_result.is_err() {
-                let error = module_result.err().unwrap();
-                return Err(error.to_string());
+    bitcode.filter(|bitcode| is_bitcode(bitcode))
+}
+
+/// Read the members of GNU or BSD static archive as pairs of name and content
+fn read_archive_members(path: &str, content: &[u8]) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let invalid_archive = || format!("File {path} is not a valid archive");
+
+    let mut members: Vec<(String, Vec<u8>)> = vec![];
+    let mut long_names: &[u8] = &[];
+    let mut offset = ARCHIVE_MAGIC.len();
+
+    while offset + ARCHIVE_HEADER_SIZE <= content.len() {
+        let header = &content[offset..offset + ARCHIVE_HEADER_SIZE];
+        let name = String::from_utf8_lossy(&header[0..16])
+            .trim_end()
+            .to_string();
+        let size = String::from_utf8_lossy(&header[48..58])
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| invalid_archive())?;
+
+        let data_start = offset + ARCHIVE_HEADER_SIZE;
+        let data_end = data_start + size;
+        if data_end > content.len() {
+            return Err(invalid_archive());
+        }
+
+        let mut data = &content[data_start..data_end];
+
+        // Members are aligned to even offsets
+        offset = data_end + (size % 2);
+
+        // GNU symbol table and long names table
+        if name == "/" || name == "/SYM64/" || name.starts_with("__.SYMDEF") {
+            continue;
+        }
+
+        if name == "//" {
+            long_names = data;
+            continue;
+        }
+
+        let member_name = if let Some(length) = name.strip_prefix("#1/") {
+            // BSD long name is stored at the start of the member data
+            let length = length.parse::<usize>().map_err(|_| invalid_archive())?;
+            if length > data.len() {
+                return Err(invalid_archive());
             }
+            let member_name = String::from_utf8_lossy(&data[..length]);
+            let member_name = member_name.trim_end_matches('\0').to_string();
+            data = &data[length..];
+            member_name
+        } else if let Some(index) = name.strip_prefix('/') {
+            // GNU long name is an offset in the long names table
+            let index = index.parse::<usize>().map_err(|_| invalid_archive())?;
+            let names = long_names.get(index..).ok_or_else(invalid_archive)?;
+            let end = names
+                .iter()
+                .position(|c| *c == b'\n')
+                .unwrap_or(names.len());
+            String::from_utf8_lossy(&names[..end])
+                .trim_end_matches('/')
+                .to_string()
+        } else {
+            name.trim_end_matches('/').to_string()
+        };
+
+        members.push((member_name, data.to_vec()));
+    }
+
+    Ok(members)
+}
+
+/// Return true if the path is an input that can be loaded as LLVM module
+pub fn is_llvm_module_path(path: &str) -> bool {
+    if path == STDIN_PATH {
+        return true;
+    }
+
+    Path::new(path).extension().is_some_and(|extension| {
+        extension == "ll"
+            || extension == "bc"
+            || extension == "a"
+            || extension == "o"
+            || extension == "obj"
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixture_path;
+
+    /// Return the Bitcode of the `math.ll` fixture module
+    fn math_bitcode() -> Vec<u8> {
+        let content = std::fs::read(fixture_path("math.ll")).unwrap();
+        let context = Context::create();
+        let module = parse_llvm_module(&context, &content, "math.ll").unwrap();
+        module.write_bitcode_to_memory().as_slice().to_vec()
+    }
+
+    /// Return the Bitcode wrapper header followed by the Bitcode
+    fn wrapped_bitcode(bitcode: &[u8]) -> Vec<u8> {
+        let mut content = BITCODE_WRAPPER_MAGIC.to_vec();
+        for field in [0, 20, bitcode.len() as u32, 0x0100_0007] {
+            content.extend_from_slice(&field.to_le_bytes());
+        }
+        content.extend_from_slice(bitcode);
+        content
+    }
+
+    fn archive_member_header(name: &str, size: usize) -> Vec<u8> {
+        format!("{name:<16}{:<12}{:<6}{:<6}{:<8}{size:<10}`\n", 0, 0, 0, 644).into_bytes()
+    }
+
+    fn push_archive_member(archive: &mut Vec<u8>, name: &str, data: &[u8]) {
+        archive.extend(archive_member_header(name, data.len()));
+        archive.extend_from_slice(data);
+        if data.len() % 2 == 1 {
+            archive.push(b'\n');
+        }
+    }
+
+    fn write_temp_file(name: &str, content: &[u8]) -> String {
+        let path = std::env::temp_dir().join(format!("llql-parser-{}-{name}", std::process::id()));
+        std::fs::write(&path, content).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    fn module_functions(name: &str, content: &[u8]) -> Vec<String> {
+        let context = Context::create();
+        let module = parse_llvm_module(&context, content, name).unwrap();
+        module
+            .get_functions()
+            .map(|function| function.get_name().to_string_lossy().to_string())
+            .collect()
+    }
 
-            let module = module_result.ok().unwrap();
-            LLVM_MODULES.push(module.to_owned());
+    #[test]
+    fn test_bitcode_magic_and_wrapper() {
+        let bitcode = math_bitcode();
+        assert!(bitcode.starts_with(&BITCODE_MAGIC));
+        assert!(is_bitcode(&bitcode));
+        assert!(!is_bitcode(b"; ModuleID = 'math.c'"));
+        assert_eq!(extract_bitcode(&bitcode), Some(bitcode.clone()));
+
+        let wrapped = wrapped_bitcode(&bitcode);
+        assert!(is_bitcode(&wrapped));
+        assert_eq!(module_functions("wrapped.bc", &wrapped), vec!["square"]);
+
+        assert_eq!(extract_bitcode(b"not an object file"), None);
+    }
+
+    #[test]
+    fn test_extract_object_bitcode_section() {
+        let bitcode = math_bitcode();
+
+        // Objects created by adding the Bitcode of `math.ll` as `.llvmbc` or `__LLVM,__bitcode`
+        // section to an empty ELF or Mach-O object with `llvm-objcopy --add-section`
+        for fixture in ["embedded-elf.o", "embedded-macho.o"] {
+            let path = fixture_path(fixture);
+            let contents = read_llvm_modules_contents(std::slice::from_ref(&path), None).unwrap();
+            assert_eq!(contents.len(), 1);
+            assert_eq!(contents[0].0, path);
+            assert!(is_bitcode(&contents[0].1));
+            assert_eq!(module_functions(fixture, &contents[0].1), vec!["square"]);
         }
+
+        let object = std::fs::read(fixture_path("embedded-elf.o")).unwrap();
+        assert_ne!(extract_object_bitcode_section(&object), None);
+        assert_eq!(extract_object_bitcode_section(&bitcode), None);
+    }
+
+    #[test]
+    fn test_read_gnu_archive_members() {
+        let bitcode = math_bitcode();
+        let long_name = "a_very_long_member_name.o";
+
+        let mut archive = ARCHIVE_MAGIC.to_vec();
+        push_archive_member(&mut archive, "/", &[0, 0, 0, 0]);
+        push_archive_member(&mut archive, "//", format!("{long_name}/\n").as_bytes());
+        push_archive_member(&mut archive, "short.o/", &bitcode);
+        push_archive_member(&mut archive, "/0", &wrapped_bitcode(&bitcode));
+        push_archive_member(&mut archive, "notes.txt/", b"not bitcode");
+
+        let members = read_archive_members("libmath.a", &archive).unwrap();
+        let names: Vec<&str> = members.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["short.o", long_name, "notes.txt"]);
+
+        let path = write_temp_file("gnu.a", &archive);
+        let contents = read_llvm_modules_contents(std::slice::from_ref(&path), None).unwrap();
+        let names: Vec<&str> = contents.iter().map(|(name, _)| name.as_str()).collect();
+        let expected = [format!("{path}(short.o)"), format!("{path}({long_name})")];
+        assert_eq!(names, expected);
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_read_bsd_archive_members() {
+        let bitcode = math_bitcode();
+        let long_name = b"a_very_long_member_name.o\0\0\0";
+
+        let mut member = long_name.to_vec();
+        member.extend_from_slice(&bitcode);
+
+        let mut archive = ARCHIVE_MAGIC.to_vec();
+        push_archive_member(&mut archive, "__.SYMDEF SORTED", &[0, 0, 0, 0]);
+        push_archive_member(&mut archive, &format!("#1/{}", long_name.len()), &member);
+        push_archive_member(&mut archive, "short.o", &bitcode);
+
+        let members = read_archive_members("libmath.a", &archive).unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].0, "a_very_long_member_name.o");
+        assert_eq!(members[0].1, bitcode);
+        assert_eq!(members[1].0, "short.o");
+
+        // Member size that is larger than the archive
+        let mut truncated = ARCHIVE_MAGIC.to_vec();
+        truncated.extend(archive_member_header("short.o", bitcode.len()));
+        assert!(read_archive_members("libmath.a", &truncated).is_err());
+    }
+
+    #[test]
+    fn test_archive_without_bitcode() {
+        let mut archive = ARCHIVE_MAGIC.to_vec();
+        push_archive_member(&mut archive, "notes.txt/", b"not bitcode");
+
+        let path = write_temp_file("empty.a", &archive);
+        let error = read_llvm_modules_contents(std::slice::from_ref(&path), None).unwrap_err();
+        assert_eq!(error, format!("Archive {path} has no LLVM Bitcode members"));
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_read_stdin_content_from_options() {
+        let content = b"define void @main() {\n  ret void\n}\n";
+        let paths = [STDIN_PATH.to_string()];
+        let contents = read_llvm_modules_contents(&paths, Some(content)).unwrap();
+        assert_eq!(contents, vec![("<stdin>".to_string(), content.to_vec())]);
     }
-    Ok(())
 }
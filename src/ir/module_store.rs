@@ -0,0 +1,358 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+use inkwell::context::Context;
+use inkwell::llvm_sys::prelude::LLVMModuleRef;
+use inkwell::module::Module;
+
+use super::cache::ModuleCache;
+use super::module_index::ModuleIndex;
+use super::module_parser::parse_llvm_module;
+use super::module_parser::read_llvm_modules_contents;
+use super::module_parser::STDIN_PATH;
+use super::parallel::default_jobs;
+use super::parallel::parallel_map;
+
+/// LLVM module with the context that owns it
+///
+/// The module borrows the boxed context, the lifetime is extended to `'static` because both
+/// are owned by this struct. This is sound because:
+/// - The context is boxed so its address doesn't change when the struct is moved
+/// - Fields are dropped in order, so the module is disposed before its context
+/// - The module is only reachable inside the crate through `ModuleStore::module` that borrows
+///   the store, and values that outlive this borrow keep an `Arc` of the store
+struct LoadedModule {
+    module: Module<'static>,
+    _context: Box<Context>,
+}
+
+impl LoadedModule {
+    fn parse(name: &str, content: &[u8]) -> Result<Self, String> {
+        let context = Box::new(Context::create());
+        let context_ref: &'static Context = unsafe { &*(context.as_ref() as *const Context) };
+        let module = parse_llvm_module(context_ref, content, name)?;
+
+        Ok(LoadedModule {
+            module,
+            _context: context,
+        })
+    }
+}
+
+// SAFETY: `Context` and `Module` are not `Send` because LLVM contexts are not thread safe, but
+// the context is owned by this struct and only its module uses it, so moving both together
+// moves every reference to the context. The module is parsed by a `parallel_map` worker and
+// moved to the loading thread before anything else can reach it, and `LoadedModule` is still
+// not `Sync`, so two threads can't use the same context through a shared reference.
+unsafe impl Send for LoadedModule {}
+
+/// Module with the input path it loaded from, its index and the parsed module
+///
+/// Modules that their index is found in the cache are parsed only when a query needs them
+struct StoredModule {
+    name: String,
+    input: String,
+    index: ModuleIndex,
+    loaded: OnceLock<LoadedModule>,
+}
+
+/// Options of loading the modules
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoadOptions {
+    /// Number of threads to parse the modules
+    pub jobs: usize,
+    /// Read and write the module indexes in the user cache directory
+    pub use_cache: bool,
+    /// Content of the standard input module, it is read from the standard input when not set
+    pub stdin_content: Option<Arc<Vec<u8>>>,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        LoadOptions {
+            jobs: default_jobs(),
+            use_cache: false,
+            stdin_content: None,
+        }
+    }
+}
+
+/// Owns the loaded LLVM modules and their contexts
+///
+/// Values that point into the modules keep a shared reference to the store, so the
+/// modules stay alive as long as any value of them is still used
+///
+/// The store is `Send` because each module is moved with its own context, but it is not `Sync`
+/// because LLVM contexts can't be used by two threads at the same time, the data provider
+/// shares it with its workers only while each worker reads a different module
+#[derive(Default)]
+pub struct ModuleStore {
+    modules: Vec<StoredModule>,
+}
+
+impl ModuleStore {
+    /// Load LLVM IR or Bitcode modules from files, static archives or the standard input
+    pub fn load(paths: &[String]) -> Result<Self, String> {
+        Self::load_with_options(paths, &LoadOptions::default())
+    }
+
+    /// Load modules in parallel on up to `jobs` threads, each module is parsed in its own context
+    /// and the modules are kept in the same order of the inputs
+    pub fn load_with_jobs(paths: &[String], jobs: usize) -> Result<Self, String> {
+        let options = LoadOptions {
+            jobs,
+            ..Default::default()
+        };
+        Self::load_with_options(paths, &options)
+    }
+
+    /// Load modules with the options, inputs that have a valid cache entry are not parsed
+    /// until a query needs the LLVM values of them
+    pub fn load_with_options(paths: &[String], options: &LoadOptions) -> Result<Self, String> {
+        let cache = if options.use_cache {
+            ModuleCache::open()
+        } else {
+            None
+        };
+        let stdin_content = options.stdin_content.as_deref().map(Vec::as_slice);
+        Self::load_with_cache(paths, options.jobs, cache.as_ref(), stdin_content)
+    }
+
+    /// Load modules on up to `jobs` threads and read or write their indexes in the cache
+    fn load_with_cache(
+        paths: &[String],
+        jobs: usize,
+        cache: Option<&ModuleCache>,
+        stdin_content: Option<&[u8]>,
+    ) -> Result<Self, String> {
+        let mut inputs_modules: Vec<Vec<StoredModule>> = Vec::with_capacity(paths.len());
+        let mut contents: Vec<(usize, String, Vec<u8>)> = vec![];
+        for (input_index, path) in paths.iter().enumerate() {
+            let cached_modules = cache
+                .filter(|_| path != STDIN_PATH)
+                .and_then(|cache| cache.lookup(path));
+
+            if let Some(cached_modules) = cached_modules {
+                let modules = cached_modules
+                    .into_iter()
+                    .map(|(name, index)| StoredModule {
+                        name,
+                        input: path.to_string(),
+                        index,
+                        loaded: OnceLock::new(),
+                    })
+                    .collect();
+                inputs_modules.push(modules);
+                continue;
+            }
+
+            inputs_modules.push(vec![]);
+            for (name, content) in
+                read_llvm_modules_contents(std::slice::from_ref(path), stdin_content)?
+            {
+                contents.push((input_index, name, content));
+            }
+        }
+
+        let parsed_modules = parallel_map(&contents, jobs, |(_, name, content)| {
+            LoadedModule::parse(name, content).map(|loaded| {
+                let index = ModuleIndex::build(&loaded.module);
+                (loaded, index)
+            })
+        });
+
+        let mut parsed_inputs: Vec<usize> = vec![];
+        for ((input_index, name, _), parsed) in contents.iter().zip(parsed_modules) {
+            let (loaded, index) = parsed?;
+            inputs_modules[*input_index].push(StoredModule {
+                name: name.to_string(),
+                input: paths[*input_index].to_string(),
+                index,
+                loaded: OnceLock::from(loaded),
+            });
+
+            if parsed_inputs.last() != Some(input_index) {
+                parsed_inputs.push(*input_index);
+            }
+        }
+
+        if let Some(cache) = cache {
+            for input_index in parsed_inputs {
+                let path = &paths[input_index];
+                if path == STDIN_PATH {
+                    continue;
+                }
+
+                let indexes: Vec<(String, &ModuleIndex)> = inputs_modules[input_index]
+                    .iter()
+                    .map(|module| (module.name.to_string(), &module.index))
+                    .collect();
+                cache.store(path, &indexes);
+            }
+        }
+
+        let modules = inputs_modules.into_iter().flatten().collect();
+        Ok(ModuleStore { modules })
+    }
+
+    /// Parse the modules at the positions that are loaded from the cache and not parsed yet
+    pub fn ensure_parsed(&self, positions: &[usize], jobs: usize) -> Result<(), String> {
+        let pending: Vec<&StoredModule> = positions
+            .iter()
+            .map(|position| &self.modules[*position])
+            .filter(|module| module.loaded.get().is_none())
+            .collect();
+
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut inputs: Vec<String> = pending.iter().map(|m| m.input.to_string()).collect();
+        inputs.dedup();
+
+        let mut contents: HashMap<String, Vec<u8>> = HashMap::new();
+        // The standard input module is never cached, so it is always parsed while loading
+        for input in inputs {
+            contents.extend(read_llvm_modules_contents(&[input], None)?);
+        }
+
+        let mut items: Vec<(&str, &[u8])> = Vec::with_capacity(pending.len());
+        for module in &pending {
+            let Some(content) = contents.get(&module.name) else {
+                return Err(format!("Module {} is changed after loading", module.name));
+            };
+            items.push((&module.name, content));
+        }
+
+        let parsed_modules = parallel_map(&items, jobs, |(name, content)| {
+            LoadedModule::parse(name, content)
+        });
+
+        for (module, parsed) in pending.iter().zip(parsed_modules) {
+            // Other query may parsed the same module in the same time, keep the first one
+            let _ = module.loaded.set(parsed?);
+        }
+
+        Ok(())
+    }
+
+    /// Share the store with the values that point into its modules
+    ///
+    /// `Arc` is used even though the store is not `Sync`, so the shared store is neither `Send`
+    /// nor `Sync` and the compiler keeps it on the thread that loaded it. The only exception is
+    /// the data provider workers that clone it into the values of the rows they create, which is
+    /// explained at `ParallelProvider` and `ModuleRows`
+    #[allow(clippy::arc_with_non_send_sync)]
+    pub fn into_shared(self) -> Arc<Self> {
+        Arc::new(self)
+    }
+
+    /// Return the number of loaded modules
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    /// Return true if there are no loaded modules
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    /// Return the name of the module at index
+    pub(crate) fn module_name(&self, index: usize) -> &str {
+        &self.modules[index].name
+    }
+
+    /// Return the precomputed index of the module at index
+    pub(crate) fn module_index(&self, index: usize) -> &ModuleIndex {
+        &self.modules[index].index
+    }
+
+    /// Return the module at index, or `None` if it is not parsed yet
+    pub(crate) fn module(&self, index: usize) -> Option<&Module<'static>> {
+        self.modules[index]
+            .loaded
+            .get()
+            .map(|loaded| &loaded.module)
+    }
+
+    /// Return the index of the parsed module, or `None` if the module is not owned by this store
+    pub(crate) fn module_position(&self, module: LLVMModuleRef) -> Option<usize> {
+        self.modules.iter().position(|stored| {
+            stored
+                .loaded
+                .get()
+                .is_some_and(|loaded| loaded.module.as_mut_ptr() == module)
+        })
+    }
+
+    /// Return the sorted names of functions and global variables in the loaded modules
+    pub fn symbols(&self) -> Vec<String> {
+        let mut names: Vec<String> = vec![];
+        for module in &self.modules {
+            for function in &module.index.functions {
+                names.push(function.name.to_string());
+            }
+            names.extend(module.index.globals.iter().cloned());
+        }
+
+        names.sort();
+        names.dedup();
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::LLQLSession;
+
+    const SAMPLE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/sample.ll");
+    const MATH_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/math.ll");
+
+    #[test]
+    fn test_parse_only_filtered_cached_modules() {
+        let cache_dir =
+            std::env::temp_dir().join(format!("llql-module-store-{}", std::process::id()));
+        let cache = ModuleCache::with_dir(cache_dir.clone());
+        let paths = vec![SAMPLE_PATH.to_string(), MATH_PATH.to_string()];
+
+        let store = ModuleStore::load_with_cache(&paths, 1, Some(&cache), None).unwrap();
+        assert!(store.module(0).is_some() && store.module(1).is_some());
+
+        let store = ModuleStore::load_with_cache(&paths, 1, Some(&cache), None)
+            .unwrap()
+            .into_shared();
+        assert!(store.module(0).is_none() && store.module(1).is_none());
+
+        let mut session = LLQLSession::with_store(store.clone());
+        let query = "SELECT instruction FROM instructions WHERE function_name = 'square'";
+        let results = session.query(query).unwrap();
+        assert_eq!(results[0].rows.len(), 2);
+        assert!(store.module(0).is_none() && store.module(1).is_some());
+
+        let _ = std::fs::remove_dir_all(cache_dir);
+    }
+
+    #[test]
+    fn test_limit_stops_before_parsing_next_module() {
+        let cache_dir =
+            std::env::temp_dir().join(format!("llql-module-limit-{}", std::process::id()));
+        let cache = ModuleCache::with_dir(cache_dir.clone());
+        let paths = vec![SAMPLE_PATH.to_string(), MATH_PATH.to_string()];
+
+        ModuleStore::load_with_cache(&paths, 1, Some(&cache), None).unwrap();
+        let store = ModuleStore::load_with_cache(&paths, 1, Some(&cache), None)
+            .unwrap()
+            .into_shared();
+
+        let mut session = LLQLSession::with_store(store.clone());
+        let results = session
+            .query("SELECT instruction FROM instructions LIMIT 3")
+            .unwrap();
+        assert_eq!(results[0].rows.len(), 3);
+        assert!(store.module(0).is_some() && store.module(1).is_none());
+
+        let _ = std::fs::remove_dir_all(cache_dir);
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::thread;
+
+/// Return the number of threads to use when the number of jobs is not set
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |count| count.get())
+}
+
+/// Map the items on up to `jobs` threads and return the results in the same order of the items
+pub(crate) fn parallel_map<T, R, F>(items: &[T], jobs: usize, function: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let jobs = jobs.clamp(1, items.len().max(1));
+    if jobs == 1 {
+        return items.iter().map(&function).collect();
+    }
+
+    let next_index = &AtomicUsize::new(0);
+    let function = &function;
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs)
+            .map(move |_| {
+                scope.spawn(move || {
+                    let mut worker_results: Vec<(usize, R)> = vec![];
+                    loop {
+                        let index = next_index.fetch_add(1, Ordering::Relaxed);
+                        if index >= items.len() {
+                            break;
+                        }
+                        worker_results.push((index, function(&items[index])));
+                    }
+                    worker_results
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+
+    // Merge by the order of items so the output is the same for any number of jobs
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use gitql_ast::types::integer::IntType;
 use gitql_ast::types::text::TextType;
 use gitql_ast::types::DataType;
 use gitql_core::environment::Environment;
@@ -11,6 +12,8 @@ use gitql_std::window::window_functions;
 
 use crate::functions::{llvm_ir_function_signatures, llvm_ir_functions};
 
+use super::types::LLVMDataType;
+use super::types::LLVMFunctionType;
 use super::types::LLVMInstType;
 
 fn llvm_tables_fields_types() -> HashMap<&'static str, Box<dyn DataType>> {
@@ -20,6 +23,23 @@ fn llvm_tables_fields_types() -> HashMap<&'static str, Box<dyn DataType>> {
     map.insert("function_name", Box::new(TextType));
     map.insert("basic_block_name", Box::new(TextType));
     map.insert("instruction", Box::new(LLVMInstType));
+    map.insert("instruction_type", Box::new(LLVMDataType));
+    map.insert("instruction_index", Box::new(IntType));
+    map.insert("block_index", Box::new(IntType));
+
+    // Functions Table
+    map.insert("function", Box::new(LLVMFunctionType));
+    map.insert("basic_blocks_count", Box::new(IntType));
+    map.insert("instructions_count", Box::new(IntType));
+
+    // Call graph Table
+    map.insert("caller_name", Box::new(TextType));
+    map.insert("callee_name", Box::new(TextType));
+    map.insert("calls_count", Box::new(IntType));
+
+    // Opcodes Table
+    map.insert("opcode_name", Box::new(TextType));
+    map.insert("opcode_count", Box::new(IntType));
 
     map
 }
@@ -28,7 +48,31 @@ fn llvm_tables_fields_names() -> HashMap<&'static str, Vec<&'static str>> {
     let mut map = HashMap::new();
     map.insert(
         "instructions",
-        vec!["function_name", "basic_block_name", "instruction"],
+        vec![
+            "function_name",
+            "basic_block_name",
+            "instruction",
+            "instruction_type",
+            "instruction_index",
+            "block_index",
+        ],
+    );
+    map.insert(
+        "functions",
+        vec![
+            "function_name",
+            "function",
+            "basic_blocks_count",
+            "instructions_count",
+        ],
+    );
+    map.insert(
+        "call_graph",
+        vec!["caller_name", "callee_name", "calls_count"],
+    );
+    map.insert(
+        "opcodes",
+        vec!["function_name", "opcode_name", "opcode_count"],
     );
     map
 }
@@ -1,5 +1,7 @@
 use std::any::Any;
 
+use gitql_ast::types::optional::OptionType;
+use gitql_ast::types::text::TextType;
 use gitql_ast::types::DataType;
 
 #[derive(Clone)]
@@ -16,6 +18,7 @@ impl DataType for LLVMInstType {
         other.is_any()
             || other.is_variant_contains(&self_type)
             || other.as_any().downcast_ref::<LLVMInstType>().is_some()
+            || is_optional_of(other, &self_type)
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -42,6 +45,77 @@ impl DataType for LLVMDataType {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn can_perform_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(LLVMDataType), Box::new(TextType)]
+    }
+
+    fn can_perform_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(LLVMDataType), Box::new(TextType)]
+    }
+
+    fn can_perform_gt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(LLVMDataType), Box::new(TextType)]
+    }
+
+    fn can_perform_gte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(LLVMDataType), Box::new(TextType)]
+    }
+
+    fn can_perform_lt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(LLVMDataType), Box::new(TextType)]
+    }
+
+    fn can_perform_lte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(LLVMDataType), Box::new(TextType)]
+    }
+}
+
+#[derive(Clone)]
+pub struct LLVMFunctionType;
+
+impl DataType for LLVMFunctionType {
+    fn literal(&self) -> String {
+        "LLVMFunction".to_string()
+    }
+
+    #[allow(clippy::borrowed_box)]
+    fn equals(&self, other: &Box<dyn DataType>) -> bool {
+        let self_type: Box<dyn DataType> = Box::new(LLVMFunctionType);
+        other.is_any()
+            || other.is_variant_contains(&self_type)
+            || other.as_any().downcast_ref::<LLVMFunctionType>().is_some()
+            || is_optional_of(other, &self_type)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct LLVMBasicBlockType;
+
+impl DataType for LLVMBasicBlockType {
+    fn literal(&self) -> String {
+        "LLVMBasicBlock".to_string()
+    }
+
+    #[allow(clippy::borrowed_box)]
+    fn equals(&self, other: &Box<dyn DataType>) -> bool {
+        let self_type: Box<dyn DataType> = Box::new(LLVMBasicBlockType);
+        other.is_any()
+            || other.is_variant_contains(&self_type)
+            || other
+                .as_any()
+                .downcast_ref::<LLVMBasicBlockType>()
+                .is_some()
+            || is_optional_of(other, &self_type)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -117,3 +191,112 @@ impl DataType for TypeMatcherType {
         self
     }
 }
+
+#[derive(Clone)]
+pub struct FunctionMatcherType;
+
+impl DataType for FunctionMatcherType {
+    fn literal(&self) -> String {
+        "FunctionMatcher".to_string()
+    }
+
+    #[allow(clippy::borrowed_box)]
+    fn equals(&self, other: &Box<dyn DataType>) -> bool {
+        let self_type: Box<dyn DataType> = Box::new(FunctionMatcherType);
+        other.is_any()
+            || other.is_variant_contains(&self_type)
+            || other
+                .as_any()
+                .downcast_ref::<FunctionMatcherType>()
+                .is_some()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Optional type of accessors that return NULL if the value has no such property, unlike
+/// `OptionType` it keeps the comparison operators of the base type, so results can be compared
+/// like `opcode(instruction) = 'add'`
+#[derive(Clone)]
+pub struct NullableType {
+    pub base: Box<dyn DataType>,
+}
+
+impl NullableType {
+    pub fn new(base: Box<dyn DataType>) -> Self {
+        NullableType { base }
+    }
+}
+
+impl DataType for NullableType {
+    fn literal(&self) -> String {
+        format!("{}?", self.base.literal())
+    }
+
+    #[allow(clippy::borrowed_box)]
+    fn equals(&self, other: &Box<dyn DataType>) -> bool {
+        other.is_any()
+            || self.base.equals(other)
+            || other
+                .as_any()
+                .downcast_ref::<NullableType>()
+                .is_some_and(|nullable| self.base.equals(&nullable.base))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn can_perform_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        self.base.can_perform_eq_op_with()
+    }
+
+    fn can_perform_group_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        self.base.can_perform_group_eq_op_with()
+    }
+
+    fn can_perform_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        self.base.can_perform_bang_eq_op_with()
+    }
+
+    fn can_perform_group_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        self.base.can_perform_group_bang_eq_op_with()
+    }
+
+    fn can_perform_gt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        self.base.can_perform_gt_op_with()
+    }
+
+    fn can_perform_gte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        self.base.can_perform_gte_op_with()
+    }
+
+    fn can_perform_lt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        self.base.can_perform_lt_op_with()
+    }
+
+    fn can_perform_lte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        self.base.can_perform_lte_op_with()
+    }
+
+    fn can_perform_like_op_with(&self) -> Vec<Box<dyn DataType>> {
+        self.base.can_perform_like_op_with()
+    }
+
+    fn can_perform_glob_op_with(&self) -> Vec<Box<dyn DataType>> {
+        self.base.can_perform_glob_op_with()
+    }
+}
+
+/// Return true if the type is optional of the base type, so values returned from accessors
+/// such as `operand` can be passed to functions that take the base type
+#[allow(clippy::borrowed_box)]
+fn is_optional_of(data_type: &Box<dyn DataType>, base: &Box<dyn DataType>) -> bool {
+    data_type
+        .as_any()
+        .downcast_ref::<OptionType>()
+        .and_then(|optional| optional.base.as_ref())
+        .is_some_and(|optional_base| base.equals(optional_base))
+}
@@ -1,32 +1,164 @@
 use std::any::Any;
 use std::cmp::Ordering;
+use std::ffi::CStr;
 use std::ffi::CString;
+use std::sync::Arc;
 
 use gitql_ast::types::DataType;
+use gitql_core::values::boolean::BoolValue;
 use gitql_core::values::Value;
 use inkwell::llvm_sys;
+use llvm_sys::core::LLVMCountParamTypes;
+use llvm_sys::core::LLVMCountStructElementTypes;
+use llvm_sys::core::LLVMDisposeMessage;
+use llvm_sys::core::LLVMGetArrayLength2;
+use llvm_sys::core::LLVMGetBasicBlockParent;
+use llvm_sys::core::LLVMGetElementType;
+use llvm_sys::core::LLVMGetFirstBasicBlock;
+use llvm_sys::core::LLVMGetFirstFunction;
+use llvm_sys::core::LLVMGetFirstInstruction;
+use llvm_sys::core::LLVMGetGlobalParent;
+use llvm_sys::core::LLVMGetInstructionParent;
+use llvm_sys::core::LLVMGetIntTypeWidth;
+use llvm_sys::core::LLVMGetNextBasicBlock;
+use llvm_sys::core::LLVMGetNextFunction;
+use llvm_sys::core::LLVMGetNextInstruction;
+use llvm_sys::core::LLVMGetParamTypes;
+use llvm_sys::core::LLVMGetPointerAddressSpace;
+use llvm_sys::core::LLVMGetReturnType;
+use llvm_sys::core::LLVMGetStructName;
+use llvm_sys::core::LLVMGetTypeKind;
+use llvm_sys::core::LLVMGetVectorSize;
+use llvm_sys::core::LLVMIsAInstruction;
+use llvm_sys::core::LLVMIsFunctionVarArg;
+use llvm_sys::core::LLVMIsOpaqueStruct;
+use llvm_sys::core::LLVMIsPackedStruct;
+use llvm_sys::core::LLVMPrintTypeToString;
 use llvm_sys::core::LLVMPrintValueToString;
+use llvm_sys::core::LLVMStructGetTypeAtIndex;
+use llvm_sys::prelude::LLVMBasicBlockRef;
 use llvm_sys::prelude::LLVMTypeRef;
 use llvm_sys::prelude::LLVMValueRef;
+use llvm_sys::LLVMTypeKind;
 
 use crate::matchers::combine::CombineBinaryInstMatcher;
 use crate::matchers::combine::CombineUnaryInstMatcher;
+use crate::matchers::function::LLVMFunction;
+use crate::matchers::matchers_helper::llvm_basic_block_name;
+use crate::matchers::matchers_helper::llvm_value_name;
 use crate::matchers::Matcher;
 
+use super::module_store::ModuleStore;
+use super::types::FunctionMatcherType;
 use super::types::InstMatcherType;
+use super::types::LLVMBasicBlockType;
 use super::types::LLVMDataType;
+use super::types::LLVMFunctionType;
 use super::types::LLVMInstType;
 use super::types::TypeMatcherType;
 
+/// Position of the instruction inside the loaded modules, used as a stable order between runs
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LLVMInstPosition {
+    pub module_index: usize,
+    pub function_index: usize,
+    pub block_index: usize,
+    pub instruction_index: usize,
+}
+
 #[derive(Clone)]
 pub struct LLVMInstValue {
     pub llvm_value: LLVMValueRef,
+    pub position: Option<LLVMInstPosition>,
+    pub store: Arc<ModuleStore>,
 }
 
 impl LLVMInstValue {
-    pub fn new(llvm_value: LLVMValueRef) -> Self {
-        LLVMInstValue { llvm_value }
+    pub fn new(llvm_value: LLVMValueRef, store: Arc<ModuleStore>) -> Self {
+        LLVMInstValue {
+            llvm_value,
+            position: None,
+            store,
+        }
+    }
+
+    pub fn with_position(
+        llvm_value: LLVMValueRef,
+        position: LLVMInstPosition,
+        store: Arc<ModuleStore>,
+    ) -> Self {
+        LLVMInstValue {
+            llvm_value,
+            position: Some(position),
+            store,
+        }
+    }
+
+    /// Return the position of the instruction, values that are not selected from the
+    /// instructions table such as operands have their position found in their module,
+    /// and values that are not instructions such as arguments and constants have no position
+    pub fn resolve_position(&self) -> Option<LLVMInstPosition> {
+        if self.position.is_some() {
+            return self.position.clone();
+        }
+        instruction_position(&self.store, self.llvm_value)
+    }
+}
+
+/// Find the position of the instruction by walking its module, function and basic block
+fn instruction_position(
+    store: &ModuleStore,
+    instruction: LLVMValueRef,
+) -> Option<LLVMInstPosition> {
+    unsafe {
+        if LLVMIsAInstruction(instruction).is_null() {
+            return None;
+        }
+
+        let basic_block = LLVMGetInstructionParent(instruction);
+        if basic_block.is_null() {
+            return None;
+        }
+
+        let function = LLVMGetBasicBlockParent(basic_block);
+        let module = LLVMGetGlobalParent(function);
+        Some(LLVMInstPosition {
+            module_index: store.module_position(module)?,
+            function_index: llvm_list_index(
+                LLVMGetFirstFunction(module),
+                LLVMGetNextFunction,
+                function,
+            )?,
+            block_index: llvm_list_index(
+                LLVMGetFirstBasicBlock(function),
+                LLVMGetNextBasicBlock,
+                basic_block,
+            )?,
+            instruction_index: llvm_list_index(
+                LLVMGetFirstInstruction(basic_block),
+                LLVMGetNextInstruction,
+                instruction,
+            )?,
+        })
+    }
+}
+
+/// Return the index of the item in the LLVM list that starts from `first`
+fn llvm_list_index<T>(
+    first: *mut T,
+    next: unsafe extern "C" fn(*mut T) -> *mut T,
+    item: *mut T,
+) -> Option<usize> {
+    let mut index = 0;
+    let mut current = first;
+    while !current.is_null() {
+        if current == item {
+            return Some(index);
+        }
+        index += 1;
+        current = unsafe { next(current) };
     }
+    None
 }
 
 impl Value for LLVMInstValue {
@@ -47,12 +179,23 @@ impl Value for LLVMInstValue {
     }
 
     fn compare(&self, other: &Box<dyn Value>) -> Option<Ordering> {
-        if let Some(other_inst) = other.as_any().downcast_ref::<LLVMInstValue>() {
-            if self.llvm_value.eq(&other_inst.llvm_value) {
-                return Some(Ordering::Equal);
+        let other_inst = other.as_any().downcast_ref::<LLVMInstValue>()?;
+        if self.llvm_value.eq(&other_inst.llvm_value) {
+            return Some(Ordering::Equal);
+        }
+
+        match (self.resolve_position(), other_inst.resolve_position()) {
+            (Some(position), Some(other_position)) => Some(position.cmp(&other_position)),
+            // Instructions are ordered before other values such as arguments and constants
+            (Some(_), None) => Some(Ordering::Less),
+            (None, Some(_)) => Some(Ordering::Greater),
+            // Different values with the same text are ordered by address to agree with `equals`
+            (None, None) => {
+                Some(self.literal().cmp(&other_inst.literal()).then_with(|| {
+                    (self.llvm_value as usize).cmp(&(other_inst.llvm_value as usize))
+                }))
             }
         }
-        None
     }
 
     fn data_type(&self) -> Box<dyn DataType> {
@@ -67,31 +210,339 @@ impl Value for LLVMInstValue {
 #[derive(Clone)]
 pub struct LLVMTypeValue {
     pub llvm_type: LLVMTypeRef,
+    pub store: Arc<ModuleStore>,
+}
+
+impl LLVMTypeValue {
+    pub fn new(llvm_type: LLVMTypeRef, store: Arc<ModuleStore>) -> Self {
+        LLVMTypeValue { llvm_type, store }
+    }
+
+    /// Compare types by structure, or compare the LLVM type syntax with a textual type
+    #[allow(clippy::borrowed_box)]
+    fn structural_compare(&self, other: &Box<dyn Value>) -> Option<Ordering> {
+        if let Some(other_type) = other.as_any().downcast_ref::<LLVMTypeValue>() {
+            return Some(compare_llvm_types(self.llvm_type, other_type.llvm_type));
+        }
+
+        if let Some(other_text) = other.as_text() {
+            return Some(self.literal().as_str().cmp(other_text.as_str()));
+        }
+
+        None
+    }
+
+    #[allow(clippy::borrowed_box)]
+    fn compare_op(
+        &self,
+        other: &Box<dyn Value>,
+        predicate: fn(Ordering) -> bool,
+    ) -> Result<Box<dyn Value>, String> {
+        match self.structural_compare(other) {
+            Some(ordering) => Ok(Box::new(BoolValue {
+                value: predicate(ordering),
+            })),
+            None => Err("Unexpected type to compare with LLVMType".to_string()),
+        }
+    }
+}
+
+/// Compare the types by kind then by their widths, sizes and element types, so named structs
+/// with the same name but different elements in different modules are not equal
+fn compare_llvm_types(lhs: LLVMTypeRef, rhs: LLVMTypeRef) -> Ordering {
+    if lhs == rhs {
+        return Ordering::Equal;
+    }
+
+    unsafe {
+        let kind = LLVMGetTypeKind(lhs);
+        let kind_order = (kind as i32).cmp(&(LLVMGetTypeKind(rhs) as i32));
+        if kind_order.is_ne() {
+            return kind_order;
+        }
+
+        // Elements are compared recursively, pointers are opaque so types has no cycles
+        match kind {
+            LLVMTypeKind::LLVMIntegerTypeKind => {
+                LLVMGetIntTypeWidth(lhs).cmp(&LLVMGetIntTypeWidth(rhs))
+            }
+            LLVMTypeKind::LLVMPointerTypeKind => {
+                LLVMGetPointerAddressSpace(lhs).cmp(&LLVMGetPointerAddressSpace(rhs))
+            }
+            LLVMTypeKind::LLVMArrayTypeKind => LLVMGetArrayLength2(lhs)
+                .cmp(&LLVMGetArrayLength2(rhs))
+                .then_with(|| compare_llvm_types(LLVMGetElementType(lhs), LLVMGetElementType(rhs))),
+            LLVMTypeKind::LLVMVectorTypeKind | LLVMTypeKind::LLVMScalableVectorTypeKind => {
+                LLVMGetVectorSize(lhs)
+                    .cmp(&LLVMGetVectorSize(rhs))
+                    .then_with(|| {
+                        compare_llvm_types(LLVMGetElementType(lhs), LLVMGetElementType(rhs))
+                    })
+            }
+            LLVMTypeKind::LLVMStructTypeKind => {
+                let name = |struct_type| {
+                    let name = LLVMGetStructName(struct_type);
+                    (!name.is_null()).then(|| CStr::from_ptr(name).to_bytes().to_vec())
+                };
+                name(lhs)
+                    .cmp(&name(rhs))
+                    .then_with(|| LLVMIsPackedStruct(lhs).cmp(&LLVMIsPackedStruct(rhs)))
+                    .then_with(|| LLVMIsOpaqueStruct(lhs).cmp(&LLVMIsOpaqueStruct(rhs)))
+                    .then_with(|| {
+                        compare_llvm_types_lists(
+                            struct_element_types(lhs),
+                            struct_element_types(rhs),
+                        )
+                    })
+            }
+            LLVMTypeKind::LLVMFunctionTypeKind => LLVMIsFunctionVarArg(lhs)
+                .cmp(&LLVMIsFunctionVarArg(rhs))
+                .then_with(|| compare_llvm_types(LLVMGetReturnType(lhs), LLVMGetReturnType(rhs)))
+                .then_with(|| {
+                    compare_llvm_types_lists(function_param_types(lhs), function_param_types(rhs))
+                }),
+            // Target extension types are compared by their name and parameters
+            LLVMTypeKind::LLVMTargetExtTypeKind => {
+                llvm_type_literal(lhs).cmp(&llvm_type_literal(rhs))
+            }
+            // Other kinds such as `float`, `void` and `label` have no structure
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+fn compare_llvm_types_lists(lhs: Vec<LLVMTypeRef>, rhs: Vec<LLVMTypeRef>) -> Ordering {
+    lhs.len().cmp(&rhs.len()).then_with(|| {
+        lhs.iter()
+            .zip(rhs.iter())
+            .map(|(lhs, rhs)| compare_llvm_types(*lhs, *rhs))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    })
+}
+
+fn struct_element_types(struct_type: LLVMTypeRef) -> Vec<LLVMTypeRef> {
+    unsafe {
+        let count = LLVMCountStructElementTypes(struct_type);
+        (0..count)
+            .map(|index| LLVMStructGetTypeAtIndex(struct_type, index))
+            .collect()
+    }
+}
+
+fn function_param_types(function_type: LLVMTypeRef) -> Vec<LLVMTypeRef> {
+    unsafe {
+        let count = LLVMCountParamTypes(function_type) as usize;
+        let mut param_types: Vec<LLVMTypeRef> = vec![std::ptr::null_mut(); count];
+        LLVMGetParamTypes(function_type, param_types.as_mut_ptr());
+        param_types
+    }
+}
+
+fn llvm_type_literal(llvm_type: LLVMTypeRef) -> String {
+    unsafe {
+        let type_str = LLVMPrintTypeToString(llvm_type);
+        let literal = CStr::from_ptr(type_str).to_string_lossy().to_string();
+        LLVMDisposeMessage(type_str);
+        literal
+    }
 }
 
 impl Value for LLVMTypeValue {
     fn literal(&self) -> String {
-        format!("{:?}", self.llvm_type)
+        llvm_type_literal(self.llvm_type)
     }
 
     fn equals(&self, other: &Box<dyn Value>) -> bool {
-        if let Some(other_inst) = other.as_any().downcast_ref::<LLVMTypeValue>() {
-            return self.llvm_type.eq(&other_inst.llvm_type);
+        self.structural_compare(other) == Some(Ordering::Equal)
+    }
+
+    fn compare(&self, other: &Box<dyn Value>) -> Option<Ordering> {
+        self.structural_compare(other)
+    }
+
+    fn data_type(&self) -> Box<dyn DataType> {
+        Box::new(LLVMDataType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        self.compare_op(other, Ordering::is_eq)
+    }
+
+    fn bang_eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        self.compare_op(other, Ordering::is_ne)
+    }
+
+    fn gt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        self.compare_op(other, Ordering::is_gt)
+    }
+
+    fn gte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        self.compare_op(other, Ordering::is_ge)
+    }
+
+    fn lt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        self.compare_op(other, Ordering::is_lt)
+    }
+
+    fn lte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        self.compare_op(other, Ordering::is_le)
+    }
+}
+
+#[derive(Clone)]
+pub struct LLVMFunctionValue {
+    pub llvm_function: LLVMValueRef,
+    pub store: Arc<ModuleStore>,
+}
+
+impl LLVMFunctionValue {
+    pub fn new(llvm_function: LLVMValueRef, store: Arc<ModuleStore>) -> Self {
+        LLVMFunctionValue {
+            llvm_function,
+            store,
+        }
+    }
+
+    /// Return the position of the function as module index and function index
+    fn position(&self) -> Option<(usize, usize)> {
+        function_position(&self.store, self.llvm_function)
+    }
+}
+
+/// Find the position of the function as module index and function index in the module
+fn function_position(store: &ModuleStore, function: LLVMValueRef) -> Option<(usize, usize)> {
+    unsafe {
+        let module = LLVMGetGlobalParent(function);
+        let module_index = store.module_position(module)?;
+        let function_index =
+            llvm_list_index(LLVMGetFirstFunction(module), LLVMGetNextFunction, function)?;
+        Some((module_index, function_index))
+    }
+}
+
+/// Return the function name qualified by the name of its module, `file:@function`
+fn qualified_function_name(store: &ModuleStore, function: LLVMValueRef) -> String {
+    let function_name = llvm_value_name(&function);
+    match function_position(store, function) {
+        Some((module_index, _)) => format!("{}:@{function_name}", store.module_name(module_index)),
+        None => format!("@{function_name}"),
+    }
+}
+
+impl Value for LLVMFunctionValue {
+    fn literal(&self) -> String {
+        qualified_function_name(&self.store, self.llvm_function)
+    }
+
+    fn equals(&self, other: &Box<dyn Value>) -> bool {
+        if let Some(other_function) = other.as_any().downcast_ref::<LLVMFunctionValue>() {
+            return self.llvm_function.eq(&other_function.llvm_function);
         }
         false
     }
 
     fn compare(&self, other: &Box<dyn Value>) -> Option<Ordering> {
-        if let Some(other_type) = other.as_any().downcast_ref::<LLVMTypeValue>() {
-            if self.llvm_type.eq(&other_type.llvm_type) {
-                return Some(Ordering::Equal);
+        let other_function = other.as_any().downcast_ref::<LLVMFunctionValue>()?;
+        if self.llvm_function.eq(&other_function.llvm_function) {
+            return Some(Ordering::Equal);
+        }
+
+        // Functions are ordered by position, different functions are never equal
+        let address = |function: LLVMValueRef| function as usize;
+        Some(
+            self.position()
+                .cmp(&other_function.position())
+                .then_with(|| {
+                    address(self.llvm_function).cmp(&address(other_function.llvm_function))
+                }),
+        )
+    }
+
+    fn data_type(&self) -> Box<dyn DataType> {
+        Box::new(LLVMFunctionType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct LLVMBasicBlockValue {
+    pub basic_block: LLVMBasicBlockRef,
+    pub store: Arc<ModuleStore>,
+}
+
+impl LLVMBasicBlockValue {
+    pub fn new(basic_block: LLVMBasicBlockRef, store: Arc<ModuleStore>) -> Self {
+        LLVMBasicBlockValue { basic_block, store }
+    }
+
+    /// Return the position of the basic block as module, function and block index
+    fn position(&self) -> Option<(usize, usize, usize)> {
+        unsafe {
+            let function = LLVMGetBasicBlockParent(self.basic_block);
+            let (module_index, function_index) = function_position(&self.store, function)?;
+            let block_index = llvm_list_index(
+                LLVMGetFirstBasicBlock(function),
+                LLVMGetNextBasicBlock,
+                self.basic_block,
+            )?;
+            Some((module_index, function_index, block_index))
+        }
+    }
+}
+
+impl Value for LLVMBasicBlockValue {
+    fn literal(&self) -> String {
+        unsafe {
+            let function = LLVMGetBasicBlockParent(self.basic_block);
+            let function_name = qualified_function_name(&self.store, function);
+            let block_name = llvm_basic_block_name(&self.basic_block);
+            if !block_name.is_empty() {
+                return format!("{function_name}:%{block_name}");
             }
+
+            // Unnamed basic blocks are printed with their index in the function
+            let index = llvm_list_index(
+                LLVMGetFirstBasicBlock(function),
+                LLVMGetNextBasicBlock,
+                self.basic_block,
+            )
+            .unwrap_or_default();
+            format!("{function_name}:#{index}")
         }
-        None
+    }
+
+    fn equals(&self, other: &Box<dyn Value>) -> bool {
+        if let Some(other_block) = other.as_any().downcast_ref::<LLVMBasicBlockValue>() {
+            return self.basic_block.eq(&other_block.basic_block);
+        }
+        false
+    }
+
+    fn compare(&self, other: &Box<dyn Value>) -> Option<Ordering> {
+        let other_block = other.as_any().downcast_ref::<LLVMBasicBlockValue>()?;
+        if self.basic_block.eq(&other_block.basic_block) {
+            return Some(Ordering::Equal);
+        }
+
+        // Basic blocks are ordered by position, different blocks are never equal
+        let address = |basic_block: LLVMBasicBlockRef| basic_block as usize;
+        Some(
+            self.position()
+                .cmp(&other_block.position())
+                .then_with(|| address(self.basic_block).cmp(&address(other_block.basic_block))),
+        )
     }
 
     fn data_type(&self) -> Box<dyn DataType> {
-        Box::new(LLVMDataType)
+        Box::new(LLVMBasicBlockType)
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -106,7 +557,7 @@ pub struct InstMatcherValue {
 
 impl Value for InstMatcherValue {
     fn literal(&self) -> String {
-        "InstMatcherValue".to_string()
+        self.matcher.literal()
     }
 
     fn equals(&self, _other: &Box<dyn Value>) -> bool {
@@ -174,7 +625,7 @@ pub struct TypeMatcherValue {
 
 impl Value for TypeMatcherValue {
     fn literal(&self) -> String {
-        "TypeMatcherValue".to_string()
+        self.matcher.literal()
     }
 
     fn equals(&self, _other: &Box<dyn Value>) -> bool {
@@ -193,3 +644,152 @@ impl Value for TypeMatcherValue {
         self
     }
 }
+
+#[derive(Clone)]
+pub struct FunctionMatcherValue {
+    pub matcher: Box<dyn Matcher<LLVMFunction>>,
+}
+
+impl Value for FunctionMatcherValue {
+    fn literal(&self) -> String {
+        self.matcher.literal()
+    }
+
+    fn equals(&self, _other: &Box<dyn Value>) -> bool {
+        false
+    }
+
+    fn compare(&self, _other: &Box<dyn Value>) -> Option<Ordering> {
+        None
+    }
+
+    fn data_type(&self) -> Box<dyn DataType> {
+        Box::new(FunctionMatcherType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use gitql_core::values::text::TextValue;
+    use gitql_core::values::Value;
+    use inkwell::context::Context;
+    use inkwell::types::AsTypeRef;
+    use inkwell::AddressSpace;
+
+    use super::LLVMTypeValue;
+    use crate::ir::module_store::ModuleStore;
+    use crate::test_utils;
+
+    fn query_literals(query: &str) -> Vec<String> {
+        test_utils::query_column("sample.ll", query)
+    }
+
+    #[test]
+    fn test_instruction_order() {
+        let instructions = query_literals("SELECT instruction FROM instructions");
+        let mut descending =
+            query_literals("SELECT instruction FROM instructions ORDER BY instruction DESC");
+        descending.reverse();
+        assert_eq!(instructions, descending);
+    }
+
+    #[test]
+    fn test_operand_order() {
+        // Operands have no position from the table, their position is found in the module
+        let operands = query_literals(
+            "SELECT operand(instruction, 0) AS lhs FROM instructions \
+             WHERE m_inst(instruction, m_add()) ORDER BY lhs",
+        );
+        assert_eq!(
+            operands,
+            vec![
+                "  %mul = mul i32 %a, 3",
+                "  %i = phi i32 [ 0, %entry ], [ %next, %loop ]"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_distinct_instructions() {
+        // `call void @free(ptr %p)` is in three places, and they are different instructions
+        let calls = test_utils::query_column(
+            "context.ll",
+            "SELECT DISTINCT instruction FROM instructions WHERE m_inst(instruction, m_call())",
+        );
+        assert_eq!(calls.len(), 4);
+
+        let blocks = test_utils::query_column(
+            "context.ll",
+            "SELECT DISTINCT basic_block_name FROM instructions",
+        );
+        assert_eq!(blocks, vec!["entry", "fail", "done"]);
+    }
+
+    #[test]
+    fn test_function_literal_and_order() {
+        let functions = query_literals("SELECT function FROM functions ORDER BY function DESC");
+        let names: Vec<String> = ["loop", "malloc", "div", "add_mul"]
+            .iter()
+            .map(|name| format!("{}:@{name}", test_utils::fixture_path("sample.ll")))
+            .collect();
+        assert_eq!(functions, names);
+    }
+
+    #[test]
+    fn test_basic_block_literal() {
+        let blocks = query_literals(
+            "SELECT parent_block(instruction) FROM instructions \
+             WHERE m_inst(instruction, m_icmp_eq())",
+        );
+        let sample_path = test_utils::fixture_path("sample.ll");
+        assert_eq!(blocks, vec![format!("{sample_path}:@loop:%loop")]);
+    }
+
+    #[test]
+    fn test_type_structural_compare() {
+        let store = ModuleStore::default().into_shared();
+        let first = Context::create();
+        let second = Context::create();
+        let type_value = |llvm_type: &dyn AsTypeRef| -> Box<dyn Value> {
+            Box::new(LLVMTypeValue::new(llvm_type.as_type_ref(), store.clone()))
+        };
+
+        // Same literal struct in different contexts
+        let first_struct = first.struct_type(
+            &[
+                first.i32_type().into(),
+                first.ptr_type(AddressSpace::default()).into(),
+            ],
+            false,
+        );
+        let second_struct = second.struct_type(
+            &[
+                second.i32_type().into(),
+                second.ptr_type(AddressSpace::default()).into(),
+            ],
+            false,
+        );
+        assert!(type_value(&first_struct).equals(&type_value(&second_struct)));
+
+        // Named structs with the same name and different elements
+        let first_named = first.opaque_struct_type("struct.A");
+        first_named.set_body(&[first.i32_type().into()], false);
+        let second_named = second.opaque_struct_type("struct.A");
+        second_named.set_body(&[second.i64_type().into()], false);
+        assert!(!type_value(&first_named).equals(&type_value(&second_named)));
+        assert_eq!(
+            type_value(&first_named).compare(&type_value(&second_named)),
+            Some(Ordering::Less)
+        );
+
+        let vector = type_value(&first.f32_type().vec_type(4));
+        let text: Box<dyn Value> = Box::new(TextValue::new("<4 x float>".to_string()));
+        assert!(vector.equals(&text));
+    }
+}
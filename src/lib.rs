@@ -0,0 +1,21 @@
+//! LLQL is a SQL like query language to run on LLVM IR and Bitcode modules
+//!
+//! ```no_run
+//! let files = vec!["main.ll".to_string()];
+//! let results = llql::query::run_query(&files, "SELECT function_name FROM functions").unwrap();
+//! for row in results[0].rows.iter() {
+//!     println!("{}", row[0].literal());
+//! }
+//! ```
+
+pub mod check;
+pub mod functions;
+pub mod inputs;
+pub mod ir;
+pub mod matchers;
+pub mod pattern;
+pub mod query;
+pub mod sarif;
+
+#[cfg(test)]
+mod test_utils;
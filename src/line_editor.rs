@@ -1,3 +1,8 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use gitql_core::environment::Environment;
 use lineeditor::event::LineEditorEvent;
 use lineeditor::keybindings::KeyCombination;
 use lineeditor::style::Style;
@@ -11,6 +16,8 @@ use lineeditor::LineEditor;
 use lineeditor::Span;
 use lineeditor::StringPrompt;
 use lineeditor::Suggestion;
+use llql::functions::llvm_ir_function_signatures;
+use llql::ir::module_store::ModuleStore;
 
 const GITQL_RESERVED_KEYWORDS: [&str; 57] = [
     "do",
@@ -72,51 +79,185 @@ const GITQL_RESERVED_KEYWORDS: [&str; 57] = [
     "array",
 ];
 
+/// Words and functions signatures used for completion and hints, updated when the loaded modules changes
 #[derive(Default)]
-pub struct GitQLHinter {}
+pub struct CompletionContext {
+    pub words: Vec<String>,
+    pub signatures: HashMap<String, String>,
+}
+
+impl CompletionContext {
+    pub fn new(env: &Environment, store: &ModuleStore) -> Self {
+        let mut words: Vec<String> = vec![];
+
+        // Tables and columns names
+        for (table, columns) in env.schema.tables_fields_names.iter() {
+            words.push(table.to_string());
+            words.extend(columns.iter().map(|column| column.to_string()));
+        }
+
+        // Functions names with their parameters types
+        let mut signatures: HashMap<String, String> = HashMap::new();
+        for (name, signature) in llvm_ir_function_signatures() {
+            let parameters: Vec<String> = signature
+                .parameters
+                .iter()
+                .map(|parameter| parameter.literal())
+                .collect();
+            signatures.insert(
+                name.to_string(),
+                format!("{name}({})", parameters.join(", ")),
+            );
+            words.push(name.to_string());
+        }
+
+        // Functions and global variables names from the loaded modules
+        words.extend(store.symbols());
+
+        words.sort();
+        words.dedup();
+        CompletionContext { words, signatures }
+    }
+}
+
+pub type SharedCompletionContext = Arc<RwLock<CompletionContext>>;
+
+/// Return the identifier before the end of the buffer, including `_` which is used in matchers names
+fn last_identifier(chars: &[char]) -> Option<String> {
+    let start = chars
+        .iter()
+        .rposition(|c| !(c.is_alphanumeric() || *c == '_'))
+        .map_or(0, |position| position + 1);
+
+    if start >= chars.len() || !(chars[start].is_alphabetic() || chars[start] == '_') {
+        return None;
+    }
+
+    Some(chars[start..].iter().collect())
+}
+
+/// Return the name of the innermost function call that is not closed yet
+fn enclosing_function_call(chars: &[char]) -> Option<String> {
+    let mut calls_stack: Vec<Option<String>> = vec![];
+    let mut i: usize = 0;
+    while i < chars.len() {
+        if chars[i] == '"' || chars[i] == '\'' {
+            let quote = chars[i];
+            i += 1;
+            while i < chars.len() && chars[i] != quote {
+                i += 1;
+            }
+            i += 1;
+            continue;
+        }
+
+        if chars[i] == '(' {
+            calls_stack.push(last_identifier(&chars[..i]));
+        } else if chars[i] == ')' {
+            calls_stack.pop();
+        }
+        i += 1;
+    }
+    calls_stack.pop().flatten()
+}
+
+pub struct GitQLHinter {
+    context: SharedCompletionContext,
+}
+
+impl GitQLHinter {
+    pub fn new(context: SharedCompletionContext) -> Self {
+        GitQLHinter { context }
+    }
+}
 
 impl Hinter for GitQLHinter {
     fn hint(&self, buffer: &mut StyledBuffer) -> Option<StyledBuffer> {
-        if let Some(keyword) = buffer.last_alphabetic_keyword() {
+        let mut style = Style::default();
+        style.set_foreground_color(Color::DarkGrey);
+
+        let chars = buffer.buffer().clone();
+        let context = self.context.read().ok()?;
+
+        if let Some(keyword) = last_identifier(&chars) {
             let keyword_lower = keyword.to_lowercase();
             for word in GITQL_RESERVED_KEYWORDS {
                 if word.starts_with(&keyword_lower) {
                     let hint = &word[keyword.len()..];
                     let mut styled_buffer = StyledBuffer::default();
-                    let mut style = Style::default();
-                    style.set_foreground_color(Color::DarkGrey);
                     styled_buffer.insert_styled_string(hint, style);
                     return Some(styled_buffer);
                 }
             }
+
+            for word in context.words.iter() {
+                if word.starts_with(&keyword) {
+                    let hint = &word[keyword.len()..];
+                    let mut styled_buffer = StyledBuffer::default();
+                    styled_buffer.insert_styled_string(hint, style);
+                    return Some(styled_buffer);
+                }
+            }
+            return None;
         }
-        None
+
+        // Show the function signature while typing the arguments
+        let function_name = enclosing_function_call(&chars)?;
+        let signature = context.signatures.get(&function_name)?;
+        let mut styled_buffer = StyledBuffer::default();
+        styled_buffer.insert_styled_string(&format!("  {signature}"), style);
+        Some(styled_buffer)
     }
 }
 
-pub struct FixedCompleter;
+pub struct GitQLCompleter {
+    context: SharedCompletionContext,
+}
+
+impl GitQLCompleter {
+    pub fn new(context: SharedCompletionContext) -> Self {
+        GitQLCompleter { context }
+    }
+}
 
-impl Completer for FixedCompleter {
+impl Completer for GitQLCompleter {
     fn complete(&self, input: &StyledBuffer) -> Vec<Suggestion> {
         let mut suggestions: Vec<Suggestion> = vec![];
         if input.position() != input.len() {
             return suggestions;
         }
 
-        if let Some(keyword) = input.last_alphabetic_keyword() {
-            for reserved_keyword in GITQL_RESERVED_KEYWORDS {
-                if reserved_keyword.starts_with(&keyword) {
-                    let suggestion = Suggestion {
-                        content: StyledBuffer::from(reserved_keyword),
-                        span: Span {
-                            start: input.len() - keyword.len(),
-                            end: input.len(),
-                        },
-                    };
-                    suggestions.push(suggestion);
+        let chars: Vec<char> = input.literal().chars().collect();
+        let Some(keyword) = last_identifier(&chars) else {
+            return suggestions;
+        };
+
+        let span = || Span {
+            start: input.len() - keyword.chars().count(),
+            end: input.len(),
+        };
+
+        let keyword_lower = keyword.to_lowercase();
+        for reserved_keyword in GITQL_RESERVED_KEYWORDS {
+            if reserved_keyword.starts_with(&keyword_lower) {
+                suggestions.push(Suggestion {
+                    content: StyledBuffer::from(reserved_keyword),
+                    span: span(),
+                });
+            }
+        }
+
+        if let Ok(context) = self.context.read() {
+            for word in context.words.iter() {
+                if word.starts_with(&keyword) {
+                    suggestions.push(Suggestion {
+                        content: StyledBuffer::from(word.as_str()),
+                        span: span(),
+                    });
                 }
             }
         }
+
         suggestions
     }
 }
@@ -179,6 +320,51 @@ impl Highlighter for GitQLHighlighter {
     }
 }
 
+/// Call the visitor with the index and character of every bracket outside string literals
+fn for_each_bracket(chars: &[char], mut visitor: impl FnMut(usize, char)) {
+    let mut i: usize = 0;
+    loop {
+        if i >= chars.len() {
+            break;
+        }
+
+        if matches!(chars[i], '"' | '\'') {
+            let quote = chars[i];
+            i += 1;
+            while i < chars.len() && chars[i] != quote {
+                i += 1;
+            }
+
+            if i < chars.len() {
+                i += 1;
+            }
+            continue;
+        }
+
+        if matches!(chars[i], '(' | '<' | '[' | '{' | ')' | '>' | ']' | '}') {
+            visitor(i, chars[i]);
+        }
+        i += 1;
+    }
+}
+
+/// Return true if the input is a complete query that ends with `;` or has balanced brackets,
+/// `<` and `>` are ignored because they are also comparison operators
+pub fn is_complete_input(input: &str) -> bool {
+    if input.trim_end().ends_with(';') {
+        return true;
+    }
+
+    let chars: Vec<char> = input.chars().collect();
+    let mut depth: i64 = 0;
+    for_each_bracket(&chars, |_, bracket| match bracket {
+        '(' | '[' | '{' => depth += 1,
+        ')' | ']' | '}' => depth -= 1,
+        _ => {}
+    });
+    depth <= 0
+}
+
 #[derive(Default)]
 pub struct MatchingBracketsHighlighter;
 
@@ -189,61 +375,28 @@ impl Highlighter for MatchingBracketsHighlighter {
         let mut current_color_index = 0;
 
         let lines = buffer.buffer().clone();
-        let mut i: usize = 0;
-        loop {
-            if i >= lines.len() {
-                break;
-            }
-
-            if lines[i] == '"' {
-                i += 1;
-                while i < lines.len() && lines[i] != '"' {
-                    i += 1;
-                }
-
-                if i < lines.len() {
-                    i += 1;
-                }
-                continue;
-            }
-
-            if lines[i] == '(' || lines[i] == '<' || lines[i] == '[' || lines[i] == '{' {
+        for_each_bracket(&lines, |i, bracket| {
+            let color = if matches!(bracket, '(' | '<' | '[' | '{') {
                 if current_color_index >= colors.len() {
                     current_color_index = 0;
                 }
 
                 let color = colors[current_color_index];
                 current_color_index += 1;
-
                 brackets_stack.push(color);
-
-                let mut style = Style::default();
-                style.set_foreground_color(color);
-                buffer.style_char(i, style);
-                i += 1;
-                continue;
-            }
-
-            if lines[i] == ')' || lines[i] == '>' || lines[i] == ']' || lines[i] == '}' {
-                let color = if brackets_stack.is_empty() {
-                    colors[0]
-                } else {
-                    brackets_stack.pop().unwrap()
-                };
-
-                let mut style = Style::default();
-                style.set_foreground_color(color);
-                buffer.style_char(i, style);
-
-                i += 1;
-                continue;
-            }
-            i += 1;
-        }
+                color
+            } else {
+                brackets_stack.pop().unwrap_or(colors[0])
+            };
+
+            let mut style = Style::default();
+            style.set_foreground_color(color);
+            buffer.style_char(i, style);
+        });
     }
 }
 
-pub fn create_new_line_editor() -> LineEditor {
+pub fn create_new_line_editor(context: SharedCompletionContext) -> LineEditor {
     let prompt = StringPrompt::new("llql > ".to_string());
     let mut line_editor = LineEditor::new(Box::new(prompt));
 
@@ -253,8 +406,8 @@ pub fn create_new_line_editor() -> LineEditor {
 
     line_editor.add_highlighter(Box::<GitQLHighlighter>::default());
     line_editor.add_highlighter(Box::<MatchingBracketsHighlighter>::default());
-    line_editor.add_hinter(Box::<GitQLHinter>::default());
-    line_editor.set_completer(Box::new(FixedCompleter));
+    line_editor.add_hinter(Box::new(GitQLHinter::new(context.clone())));
+    line_editor.set_completer(Box::new(GitQLCompleter::new(context)));
 
     let bindings = line_editor.keybinding();
     bindings.register_common_control_bindings();
@@ -272,3 +425,53 @@ pub fn create_new_line_editor() -> LineEditor {
 
     line_editor
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn test_last_identifier() {
+        assert_eq!(
+            last_identifier(&chars("SELECT m_ad")),
+            Some("m_ad".to_string())
+        );
+        assert_eq!(last_identifier(&chars("_inst")), Some("_inst".to_string()));
+        assert_eq!(last_identifier(&chars("m_inst(i2")), Some("i2".to_string()));
+        assert_eq!(last_identifier(&chars("SELECT ")), None);
+        assert_eq!(last_identifier(&chars("LIMIT 10")), None);
+        assert_eq!(last_identifier(&chars("")), None);
+    }
+
+    #[test]
+    fn test_is_complete_input() {
+        assert!(is_complete_input("SELECT * FROM instructions"));
+        assert!(is_complete_input(
+            "SELECT m_inst(instruction, m_add()) FROM instructions"
+        ));
+        assert!(is_complete_input("SELECT m_inst(instruction, m_add(;"));
+        assert!(is_complete_input("SELECT (1 + 2) ;  \n"));
+        assert!(!is_complete_input("SELECT m_inst(instruction, m_add("));
+        assert!(!is_complete_input("SELECT [1, 2"));
+        assert!(!is_complete_input("SELECT { 'a'"));
+        assert!(is_complete_input("SELECT '(' FROM instructions"));
+    }
+
+    #[test]
+    fn test_enclosing_function_call() {
+        let call = |text: &str| enclosing_function_call(&chars(text));
+        assert_eq!(
+            call("m_inst(instruction, m_add("),
+            Some("m_add".to_string())
+        );
+        assert_eq!(call("m_inst(i, m_add(a, b), "), Some("m_inst".to_string()));
+        assert_eq!(call("m_call('(', "), Some("m_call".to_string()));
+        assert_eq!(call("m_call(\")\""), Some("m_call".to_string()));
+        assert_eq!(call("m_inst(i, m_add())"), None);
+        assert_eq!(call("SELECT (1 + "), None);
+    }
+}
@@ -1,12 +1,13 @@
 use std::fs;
 use std::io;
 use std::io::IsTerminal;
-use std::path::Path;
+use std::sync::Arc;
+use std::sync::RwLock;
 
 use arguments::Arguments;
 use arguments::Command;
+use arguments::OutputFormat;
 use gitql_cli::diagnostic_reporter;
-use gitql_cli::diagnostic_reporter::DiagnosticReporter;
 use gitql_cli::printer::BaseOutputPrinter;
 use gitql_cli::printer::CSVPrinter;
 use gitql_cli::printer::JSONPrinter;
@@ -15,21 +16,38 @@ use gitql_cli::printer::TablePrinter;
 use gitql_cli::printer::YAMLPrinter;
 use gitql_core::environment::Environment;
 use gitql_engine::data_provider::DataProvider;
-use gitql_engine::engine;
 use gitql_engine::engine::EvaluationResult::SelectedGroups;
 use gitql_parser::diagnostic::Diagnostic;
 use gitql_parser::parser;
 use gitql_parser::tokenizer::Tokenizer;
-use ir::data_provider::LLVMIRDataProvider;
-use ir::module_parser::parse_llvm_modules;
-use ir::schema::create_llql_environment;
+use history::History;
+use line_editor::CompletionContext;
 use lineeditor::LineEditorResult;
+use llql::check::run_check;
+use llql::check::Finding;
+use llql::check::Rule;
+use llql::check::Severity;
+use llql::check::CHECK_ERROR_EXIT_CODE;
+use llql::inputs;
+use llql::inputs::validate_files_paths;
+use llql::ir::data_provider::LLVMIRDataProvider;
+use llql::ir::filters::evaluate_with_filters;
+use llql::ir::module_parser::read_stdin_content;
+use llql::ir::module_parser::STDIN_PATH;
+use llql::ir::module_store::ModuleStore;
+use llql::ir::schema::create_llql_environment;
+use llql::pattern::validate_patterns;
+use llql::sarif::query_findings;
+use llql::sarif::query_rule;
+use llql::sarif::sarif_report;
+use printers::HTMLPrinter;
+use printers::MarkdownPrinter;
 
 pub mod arguments;
-pub mod functions;
-pub mod ir;
+pub mod history;
 pub mod line_editor;
-pub mod matchers;
+pub mod printers;
+pub mod repl;
 
 fn main() {
     if cfg!(debug_assertions) {
@@ -38,7 +56,18 @@ fn main() {
     }
 
     let args: Vec<String> = std::env::args().collect();
-    let command = arguments::parse_arguments(&args);
+    let mut command = arguments::parse_arguments(&args);
+
+    if let Command::ReplMode(arguments)
+    | Command::QueryMode(_, arguments)
+    | Command::ScriptMode(_, arguments)
+    | Command::CheckMode(_, arguments) = &mut command
+    {
+        if let Err(error) = resolve_arguments_files(arguments) {
+            println!("{error}");
+            std::process::exit(1);
+        }
+    }
 
     match command {
         Command::ReplMode(arguments) => {
@@ -55,19 +84,28 @@ fn main() {
                 std::process::exit(1);
             }
 
-            if let Err(parse_modules_error) = parse_llvm_modules(&arguments.files) {
-                reporter.report_diagnostic("", Diagnostic::error(parse_modules_error.as_str()));
-                std::process::exit(1);
-            }
+            let store =
+                match ModuleStore::load_with_options(&arguments.files, &arguments.load_options()) {
+                    Ok(store) => store.into_shared(),
+                    Err(parse_modules_error) => {
+                        reporter
+                            .report_diagnostic("", Diagnostic::error(parse_modules_error.as_str()));
+                        std::process::exit(1);
+                    }
+                };
 
             let mut env = create_llql_environment();
             let query =
                 fs::read_to_string(script_file).expect("Should have been able to read the file");
 
-            let provider: Box<dyn DataProvider> =
-                Box::new(LLVMIRDataProvider::new(arguments.files.clone()));
+            let provider = LLVMIRDataProvider::with_jobs(store, arguments.jobs);
 
-            execute_llql_query(query, &arguments, &mut env, &provider, &mut reporter);
+            if let Err(diagnostic) =
+                execute_llql_query(query.clone(), &arguments, &mut env, &provider)
+            {
+                reporter.report_diagnostic(&query, *diagnostic);
+                std::process::exit(1);
+            }
         }
         Command::QueryMode(query, arguments) => {
             let mut reporter = diagnostic_reporter::DiagnosticReporter::default();
@@ -78,16 +116,29 @@ fn main() {
                 std::process::exit(1);
             }
 
-            if let Err(parse_modules_error) = parse_llvm_modules(&arguments.files) {
-                reporter.report_diagnostic("", Diagnostic::error(parse_modules_error.as_str()));
-                std::process::exit(1);
-            }
+            let store =
+                match ModuleStore::load_with_options(&arguments.files, &arguments.load_options()) {
+                    Ok(store) => store.into_shared(),
+                    Err(parse_modules_error) => {
+                        reporter
+                            .report_diagnostic("", Diagnostic::error(parse_modules_error.as_str()));
+                        std::process::exit(1);
+                    }
+                };
 
             let mut env = create_llql_environment();
-            let provider: Box<dyn DataProvider> =
-                Box::new(LLVMIRDataProvider::new(arguments.files.clone()));
+            let provider = LLVMIRDataProvider::with_jobs(store, arguments.jobs);
 
-            execute_llql_query(query, &arguments, &mut env, &provider, &mut reporter);
+            if let Err(diagnostic) =
+                execute_llql_query(query.clone(), &arguments, &mut env, &provider)
+            {
+                reporter.report_diagnostic(&query, *diagnostic);
+                std::process::exit(1);
+            }
+        }
+        Command::CheckMode(rules_file, arguments) => {
+            let exit_code = run_llql_check(&rules_file, &arguments);
+            std::process::exit(exit_code);
         }
         Command::Help => {
             arguments::print_help_list();
@@ -104,43 +155,93 @@ fn main() {
 
 fn launch_llql_repl(arguments: &Arguments) {
     let mut reporter = diagnostic_reporter::DiagnosticReporter::default();
-    let files = &arguments.files;
-    if let Err(error) = validate_files_paths(files) {
+    let mut files = arguments.files.clone();
+    if let Err(error) = validate_files_paths(&files) {
         reporter.report_diagnostic("", Diagnostic::error(error.as_str()));
         std::process::exit(1);
     }
 
-    if let Err(parse_modules_error) = parse_llvm_modules(files) {
-        reporter.report_diagnostic("", Diagnostic::error(parse_modules_error.as_str()));
-        std::process::exit(1);
+    // Keep the standard input module so the REPL commands can load the files again
+    let mut load_options = arguments.load_options();
+    if files.iter().any(|file| file == STDIN_PATH) {
+        match read_stdin_content() {
+            Ok(content) => load_options.stdin_content = Some(Arc::new(content)),
+            Err(error) => {
+                reporter.report_diagnostic("", Diagnostic::error(error.as_str()));
+                std::process::exit(1);
+            }
+        }
     }
 
+    let mut store = match ModuleStore::load_with_options(&files, &load_options) {
+        Ok(store) => store.into_shared(),
+        Err(parse_modules_error) => {
+            reporter.report_diagnostic("", Diagnostic::error(parse_modules_error.as_str()));
+            std::process::exit(1);
+        }
+    };
+
     let mut global_env = create_llql_environment();
-    let provider: Box<dyn DataProvider> = Box::new(LLVMIRDataProvider::new(files.to_vec()));
+    let mut provider = LLVMIRDataProvider::with_jobs(store.clone(), arguments.jobs);
 
     // Launch the right line editor if the flag is enabled
     // Later this line editor will be the default editor
     if arguments.enable_line_editor {
-        let mut line_editor = line_editor::create_new_line_editor();
+        let completion_context = Arc::new(RwLock::new(CompletionContext::new(&global_env, &store)));
+        let mut line_editor = line_editor::create_new_line_editor(completion_context.clone());
+        let mut history = History::load();
+        let mut pending_input = String::new();
         loop {
-            if let Ok(LineEditorResult::Success(input)) = line_editor.read_line() {
+            if let Ok(LineEditorResult::Success(line)) = line_editor.read_line() {
                 println!();
 
-                if input.is_empty() || input == "\n" {
+                if pending_input.is_empty() && (line.is_empty() || line == "\n") {
                     continue;
                 }
 
-                if input == "exit" {
+                if pending_input.is_empty() && line == "exit" {
                     break;
                 }
 
-                execute_llql_query(
-                    input.to_owned(),
-                    arguments,
-                    &mut global_env,
-                    &provider,
-                    &mut reporter,
-                );
+                if pending_input.is_empty() && repl::is_repl_command(&line) {
+                    history.push(&line);
+                    if let Err(error) = repl::execute_repl_command(
+                        &line,
+                        &mut files,
+                        &mut store,
+                        &global_env,
+                        &load_options,
+                        Some(&history),
+                    ) {
+                        reporter.report_diagnostic(&line, Diagnostic::error(&error));
+                    }
+                    provider = LLVMIRDataProvider::with_jobs(store.clone(), arguments.jobs);
+
+                    // Loaded modules may changed, so update the completion symbols
+                    if let Ok(mut context) = completion_context.write() {
+                        *context = CompletionContext::new(&global_env, &store);
+                    }
+                    continue;
+                }
+
+                // Continue reading lines until the query ends with `;` or brackets are balanced
+                if !pending_input.is_empty() {
+                    pending_input.push('\n');
+                }
+                pending_input.push_str(&line);
+                if !line_editor::is_complete_input(&pending_input) {
+                    continue;
+                }
+
+                let input = std::mem::take(&mut pending_input);
+                history.push(&input);
+
+                // Report the failure and keep the session alive
+                if let Err(diagnostic) =
+                    execute_llql_query(input.to_owned(), arguments, &mut global_env, &provider)
+                {
+                    reporter.report_diagnostic(&input, *diagnostic);
+                }
 
                 global_env.clear_session();
             }
@@ -179,79 +280,102 @@ fn launch_llql_repl(arguments: &Arguments) {
             break;
         }
 
-        execute_llql_query(
+        if repl::is_repl_command(stdin_input) {
+            if let Err(error) = repl::execute_repl_command(
+                stdin_input,
+                &mut files,
+                &mut store,
+                &global_env,
+                &load_options,
+                None,
+            ) {
+                reporter.report_diagnostic(stdin_input, Diagnostic::error(&error));
+            }
+            provider = LLVMIRDataProvider::with_jobs(store.clone(), arguments.jobs);
+            input.clear();
+            continue;
+        }
+
+        // Report the failure and keep the session alive
+        if let Err(diagnostic) = execute_llql_query(
             stdin_input.to_owned(),
             arguments,
             &mut global_env,
             &provider,
-            &mut reporter,
-        );
+        ) {
+            reporter.report_diagnostic(stdin_input, *diagnostic);
+        }
 
         input.clear();
         global_env.clear_session();
     }
 }
 
+/// Execute LLQL query and print the result, or return the diagnostic of the failure to be reported by the caller
 #[allow(clippy::borrowed_box)]
 fn execute_llql_query(
     query: String,
     arguments: &Arguments,
     env: &mut Environment,
-    provider: &Box<dyn DataProvider>,
-    reporter: &mut DiagnosticReporter,
-) {
+    provider: &LLVMIRDataProvider,
+) -> Result<(), Box<Diagnostic>> {
     let front_start = std::time::Instant::now();
-    let tokenizer_result = Tokenizer::tokenize(&query);
-    if tokenizer_result.is_err() {
-        let diagnostic = tokenizer_result.err().unwrap();
-        reporter.report_diagnostic(&query, *diagnostic);
-        std::process::exit(1);
-    }
-
-    let tokens = tokenizer_result.ok().unwrap();
+    let tokens = Tokenizer::tokenize(&query)?;
     if tokens.is_empty() {
-        std::process::exit(1);
+        return Ok(());
     }
 
-    let parser_result = parser::parse_gql(tokens, env);
-    if parser_result.is_err() {
-        let diagnostic = parser_result.err().unwrap();
-        reporter.report_diagnostic(&query, *diagnostic);
-        std::process::exit(1);
-    }
-
-    let query_node = parser_result.ok().unwrap();
+    validate_patterns(&query, &tokens)?;
+    let query_node = parser::parse_gql(tokens, env)?;
     let front_duration = front_start.elapsed();
 
     let engine_start = std::time::Instant::now();
-    let evaluation_result = engine::evaluate(env, provider, query_node);
+    let filters = provider.filters();
+    let provider: Box<dyn DataProvider> = Box::new(provider.clone());
+    let evaluation_result = evaluate_with_filters(env, &provider, &filters, query_node);
     let engine_duration = engine_start.elapsed();
 
-    // Report Runtime exceptions if they exists
-    if evaluation_result.is_err() {
-        let exception = Diagnostic::exception(&evaluation_result.err().unwrap());
-        reporter.report_diagnostic(&query, exception);
-        std::process::exit(1);
-    }
+    // Return Runtime exceptions if they exists
+    let evaluations_results = match evaluation_result {
+        Ok(evaluations_results) => evaluations_results,
+        Err(exception) => return Err(Box::new(Diagnostic::exception(&exception))),
+    };
 
     // Render the result only if they are selected groups not any other statement
-    let printer: Box<dyn BaseOutputPrinter> = match arguments.output_format {
-        OutputFormatKind::Table => {
-            Box::new(TablePrinter::new(arguments.pagination, arguments.page_size))
-        }
-        OutputFormatKind::JSON => Box::new(JSONPrinter),
-        OutputFormatKind::CSV => Box::new(CSVPrinter),
-        OutputFormatKind::YAML => Box::new(YAMLPrinter),
+    let printer: Option<Box<dyn BaseOutputPrinter>> = match arguments.output_format {
+        OutputFormat::GitQL(OutputFormatKind::Table) => Some(Box::new(TablePrinter::new(
+            arguments.pagination,
+            arguments.page_size,
+        ))),
+        OutputFormat::GitQL(OutputFormatKind::JSON) => Some(Box::new(JSONPrinter)),
+        OutputFormat::GitQL(OutputFormatKind::CSV) => Some(Box::new(CSVPrinter)),
+        OutputFormat::GitQL(OutputFormatKind::YAML) => Some(Box::new(YAMLPrinter)),
+        OutputFormat::Markdown => Some(Box::new(MarkdownPrinter)),
+        OutputFormat::Html => Some(Box::new(HTMLPrinter)),
+        OutputFormat::Sarif => None,
     };
 
+    // Rows of all select statements are printed as one SARIF report after evaluation
+    let rule = query_rule(&query);
+    let mut findings: Vec<Finding> = vec![];
+
     // Render the result only if they are selected groups not any other statement
-    let evaluations_results = evaluation_result.ok().unwrap();
     for evaluation_result in evaluations_results {
         let mut rows_count = 0;
         if let SelectedGroups(mut groups) = evaluation_result {
             if !groups.is_empty() {
                 rows_count += groups.groups[0].len();
-                printer.print(&mut groups);
+                if let Some(printer) = &printer {
+                    printer.print(&mut groups);
+                } else {
+                    let rows = groups
+                        .groups
+                        .into_iter()
+                        .flat_map(|group| group.rows)
+                        .map(|row| row.values)
+                        .collect();
+                    findings.append(&mut query_findings(&rule, &groups.titles, rows));
+                }
             }
         }
 
@@ -262,19 +386,84 @@ fn execute_llql_query(
             );
         }
     }
+
+    if arguments.output_format == OutputFormat::Sarif {
+        print_sarif_report(&[rule], &findings);
+    }
+
+    Ok(())
 }
 
-fn validate_files_paths(files: &[String]) -> Result<(), String> {
-    for file in files {
-        if !Path::new(file).exists() {
-            return Err(format!("File ${file} is not exists"));
+/// Run the rules on the files and print the findings, then return the process exit code which is
+/// `1` if any finding is at or above the fail severity, or `2` if the rules can't run
+fn run_llql_check(rules_file: &str, arguments: &Arguments) -> i32 {
+    let check = match run_check(rules_file, &arguments.files, &arguments.load_options()) {
+        Ok(check) => check,
+        Err(error) => {
+            let mut reporter = diagnostic_reporter::DiagnosticReporter::default();
+            reporter.report_diagnostic("", Diagnostic::error(&error));
+            return CHECK_ERROR_EXIT_CODE;
         }
+    };
 
-        if file.ends_with(".ll") || file.ends_with(".bc") {
-            continue;
+    if arguments.output_format == OutputFormat::Sarif {
+        print_sarif_report(&check.rules, &check.findings);
+    } else {
+        print_findings(&check.findings);
+    }
+
+    check.exit_code(arguments.fail_on)
+}
+
+fn print_findings(findings: &[Finding]) {
+    for finding in findings {
+        println!(
+            "{}[{}]: {}",
+            finding.severity, finding.rule, finding.message
+        );
+        for (column, value) in finding.column_names.iter().zip(finding.values.iter()) {
+            println!("    {column}: {}", value.literal());
         }
+    }
 
-        return Err(format!("File ${file} must end with LL or BC extension"));
+    let count = |severity: Severity| {
+        findings
+            .iter()
+            .filter(|finding| finding.severity == severity)
+            .count()
+    };
+
+    println!(
+        "{} findings ({} errors, {} warnings, {} notes)",
+        findings.len(),
+        count(Severity::Error),
+        count(Severity::Warning),
+        count(Severity::Note)
+    );
+}
+
+fn print_sarif_report(rules: &[Rule], findings: &[Finding]) {
+    let report = sarif_report(rules, findings);
+    match serde_json::to_string_pretty(&report) {
+        Ok(report) => println!("{report}"),
+        Err(error) => eprintln!("Can't write SARIF report: {error}"),
     }
+}
+
+/// Resolve directories, response files and compilation database to the input files
+fn resolve_arguments_files(arguments: &mut Arguments) -> Result<(), String> {
+    let mut files = inputs::resolve_input_files(
+        &arguments.files,
+        &arguments.include_globs,
+        &arguments.exclude_globs,
+    )?;
+
+    if let Some(compile_commands) = &arguments.compile_commands {
+        files.extend(inputs::read_compile_commands_bitcode_files(
+            compile_commands,
+        )?);
+    }
+
+    arguments.files = files;
     Ok(())
 }
@@ -36,6 +36,30 @@ pub enum BinaryOperator {
 }
 
 impl BinaryOperator {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BinaryOperator::Any => "binop",
+            BinaryOperator::Arithmetic => "arithmetic",
+            BinaryOperator::Add => "add",
+            BinaryOperator::Sub => "sub",
+            BinaryOperator::Mul => "mul",
+            BinaryOperator::SDiv => "div",
+            BinaryOperator::SRem => "rem",
+            BinaryOperator::FAdd => "fadd",
+            BinaryOperator::FSub => "fsub",
+            BinaryOperator::FMul => "fmul",
+            BinaryOperator::FDiv => "fdiv",
+            BinaryOperator::FRem => "frem",
+            BinaryOperator::And => "and",
+            BinaryOperator::Or => "or",
+            BinaryOperator::OrDisjoint => "or_disjoint",
+            BinaryOperator::Xor => "xor",
+            BinaryOperator::LogicalShiftLeft => "shl",
+            BinaryOperator::LogicalShiftRight => "shr",
+            BinaryOperator::ArithmeticShiftRight => "ashr",
+        }
+    }
+
     pub fn match_llvm_opcode(&self, llvm_op: LLVMOpcode) -> bool {
         match llvm_op {
             LLVMOpcode::LLVMAdd => matches!(
@@ -621,4 +645,14 @@ impl Matcher<LLVMValueRef> for BinaryInstMatcher {
             false
         }
     }
+
+    fn literal(&self) -> String {
+        let prefix = if self.commutatively { "m_c_" } else { "m_" };
+        format!(
+            "{prefix}{}({}, {})",
+            self.operator.name(),
+            self.lhs_matcher.literal(),
+            self.rhs_matcher.literal()
+        )
+    }
 }
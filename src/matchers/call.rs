@@ -7,6 +7,8 @@ use inkwell::llvm_sys::core::LLVMIsAFunction;
 use inkwell::llvm_sys::prelude::LLVMValueRef;
 use inkwell::llvm_sys::LLVMOpcode;
 
+use crate::matchers::matchers_helper::text_literal;
+
 use super::Matcher;
 
 #[derive(Clone)]
@@ -38,6 +40,13 @@ impl Matcher<LLVMValueRef> for CallInstMatcher {
         }
         false
     }
+
+    fn literal(&self) -> String {
+        match &self.name {
+            Some(name) => format!("m_call({})", text_literal(name)),
+            None => "m_call()".to_string(),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -81,4 +90,11 @@ impl Matcher<LLVMValueRef> for IntrinsicInstMatcher {
             false
         }
     }
+
+    fn literal(&self) -> String {
+        match &self.name {
+            Some(name) => format!("m_intrinsic({})", text_literal(name)),
+            None => "m_intrinsic()".to_string(),
+        }
+    }
 }
@@ -153,4 +153,23 @@ impl Matcher<LLVMValueRef> for CastInstMatcher {
             true
         }
     }
+
+    fn literal(&self) -> String {
+        let name = match self.kind {
+            CastMatcherKind::Any => "m_cast",
+            CastMatcherKind::Trunc => "m_trunc",
+            CastMatcherKind::IntToPtr => "m_int_to_ptr",
+            CastMatcherKind::PtrToInt => "m_ptr_to_int",
+            CastMatcherKind::FPToUI => "m_fp_to_ui",
+            CastMatcherKind::FPToSI => "m_fp_to_si",
+            CastMatcherKind::FPTrunc => "m_fp_trunc",
+            CastMatcherKind::Ext => "m_ext",
+            CastMatcherKind::FPExt => "m_fpext",
+            CastMatcherKind::ZExt => "m_zext",
+            CastMatcherKind::SExt => "m_sext",
+            CastMatcherKind::BitCast => "m_bit_cast",
+            CastMatcherKind::AddrSpaceCast => "m_addr_space_cast",
+        };
+        format!("{name}({})", self.value_matcher.literal())
+    }
 }
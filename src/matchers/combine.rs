@@ -72,6 +72,16 @@ impl Matcher<LLVMValueRef> for CombineInstMatcher {
             CombineMatcherKind::NoneOf => matches_count == 0,
         }
     }
+
+    fn literal(&self) -> String {
+        let name = match self.matcher_kind {
+            CombineMatcherKind::OneOf => "m_inst_combine_oneof",
+            CombineMatcherKind::AllOf => "m_inst_combine_allof",
+            CombineMatcherKind::NoneOf => "m_inst_combine_noneof",
+        };
+        let matchers: Vec<String> = self.matchers.iter().map(|m| m.literal()).collect();
+        format!("{name}({})", matchers.join(", "))
+    }
 }
 
 #[derive(PartialEq, Clone)]
@@ -137,6 +147,15 @@ impl Matcher<LLVMValueRef> for CombineBinaryInstMatcher {
             }
         }
     }
+
+    fn literal(&self) -> String {
+        let name = match self.kind {
+            CombineBinaryMatcherKind::And => "m_inst_combine_and",
+            CombineBinaryMatcherKind::Or => "m_inst_combine_or",
+            CombineBinaryMatcherKind::Xor => "m_inst_combine_xor",
+        };
+        format!("{name}({}, {})", self.lhs.literal(), self.rhs.literal())
+    }
 }
 
 #[derive(Clone)]
@@ -154,4 +173,8 @@ impl Matcher<LLVMValueRef> for CombineUnaryInstMatcher {
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
         !(self.rhs.is_match(instruction))
     }
+
+    fn literal(&self) -> String {
+        format!("m_inst_combine_not({})", self.rhs.literal())
+    }
 }
@@ -16,6 +16,10 @@ impl Matcher<LLVMValueRef> for ConstExprMatcher {
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
         unsafe { !LLVMIsAConstantExpr(*instruction).is_null() }
     }
+
+    fn literal(&self) -> String {
+        "m_const_expr()".to_string()
+    }
 }
 
 #[derive(Clone)]
@@ -87,6 +91,17 @@ impl Matcher<LLVMValueRef> for ConstIntMatcher {
             true
         }
     }
+
+    fn literal(&self) -> String {
+        match &self.condition {
+            None => "m_const_int()".to_string(),
+            Some(ConstIntMatcherCondition::Specific(0)) => "m_zero()".to_string(),
+            Some(ConstIntMatcherCondition::Specific(1)) => "m_one()".to_string(),
+            Some(ConstIntMatcherCondition::Specific(value)) => format!("m_specific_int({value})"),
+            Some(ConstIntMatcherCondition::InRange(s, e)) => format!("m_range_int({s}, {e})"),
+            Some(ConstIntMatcherCondition::PowerOfTwo) => "m_power2()".to_string(),
+        }
+    }
 }
 
 /// Return instruction matcher to check if current value is a constants floating point
@@ -101,6 +116,10 @@ impl Matcher<LLVMValueRef> for ConstFloatMatcher {
             value_kind == LLVMValueKind::LLVMConstantFPValueKind
         }
     }
+
+    fn literal(&self) -> String {
+        "m_const_fp()".to_string()
+    }
 }
 
 /// Return instruction matcher to check if current value is a constants pointer null
@@ -112,6 +131,10 @@ impl Matcher<LLVMValueRef> for ConstPointerNullMatcher {
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
         unsafe { !LLVMIsAConstantFP(*instruction).is_null() }
     }
+
+    fn literal(&self) -> String {
+        "m_const_null()".to_string()
+    }
 }
 
 /// Return instruction matcher to check if current value is a constants number
@@ -126,4 +149,8 @@ impl Matcher<LLVMValueRef> for ConstNumberMatcher {
                 || !LLVMIsAConstantFP(*instruction).is_null()
         }
     }
+
+    fn literal(&self) -> String {
+        "m_const_num()".to_string()
+    }
 }
@@ -0,0 +1,182 @@
+use inkwell::llvm_sys::core::LLVMGetBasicBlockParent;
+use inkwell::llvm_sys::core::LLVMGetBasicBlockTerminator;
+use inkwell::llvm_sys::core::LLVMGetEntryBasicBlock;
+use inkwell::llvm_sys::core::LLVMGetInstructionOpcode;
+use inkwell::llvm_sys::prelude::LLVMValueRef;
+use inkwell::llvm_sys::LLVMOpcode;
+
+use crate::matchers::matchers_helper::function_has_attribute;
+use crate::matchers::matchers_helper::instruction_parent_block;
+use crate::matchers::matchers_helper::is_glob_match;
+use crate::matchers::matchers_helper::llvm_basic_block_name;
+use crate::matchers::matchers_helper::llvm_value_name;
+use crate::matchers::matchers_helper::text_literal;
+
+use super::Matcher;
+
+/// Match instruction by the function it belongs to, with optional name pattern and function attributes
+#[derive(Clone)]
+pub struct InFunctionMatcher {
+    pub name_pattern: Option<String>,
+    pub attributes: Vec<String>,
+}
+
+impl Matcher<LLVMValueRef> for InFunctionMatcher {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn is_match(&self, instruction: &LLVMValueRef) -> bool {
+        unsafe {
+            let Some(basic_block) = instruction_parent_block(instruction) else {
+                return false;
+            };
+
+            let function = LLVMGetBasicBlockParent(basic_block);
+            if function.is_null() {
+                return false;
+            }
+
+            if let Some(pattern) = &self.name_pattern {
+                if !is_glob_match(pattern, &llvm_value_name(&function)) {
+                    return false;
+                }
+            }
+
+            self.attributes
+                .iter()
+                .all(|attribute| function_has_attribute(&function, attribute))
+        }
+    }
+
+    fn literal(&self) -> String {
+        let mut arguments: Vec<String> = vec![];
+        if let Some(name_pattern) = &self.name_pattern {
+            arguments.push(text_literal(name_pattern));
+        } else if !self.attributes.is_empty() {
+            arguments.push(text_literal("*"));
+        }
+        arguments.extend(self.attributes.iter().map(|name| text_literal(name)));
+        format!("m_in_function({})", arguments.join(", "))
+    }
+}
+
+#[derive(Clone)]
+enum BasicBlockCondition {
+    Any,
+    Entry,
+    EndsWithUnreachable,
+}
+
+/// Match instruction by the basic block it belongs to, with optional name pattern and block condition
+#[derive(Clone)]
+pub struct InBasicBlockMatcher {
+    name_pattern: Option<String>,
+    condition: BasicBlockCondition,
+}
+
+impl InBasicBlockMatcher {
+    pub fn create_in_block(name_pattern: Option<String>) -> Self {
+        InBasicBlockMatcher {
+            name_pattern,
+            condition: BasicBlockCondition::Any,
+        }
+    }
+
+    pub fn create_in_entry_block() -> Self {
+        InBasicBlockMatcher {
+            name_pattern: None,
+            condition: BasicBlockCondition::Entry,
+        }
+    }
+
+    pub fn create_in_unreachable_block() -> Self {
+        InBasicBlockMatcher {
+            name_pattern: None,
+            condition: BasicBlockCondition::EndsWithUnreachable,
+        }
+    }
+}
+
+impl Matcher<LLVMValueRef> for InBasicBlockMatcher {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn is_match(&self, instruction: &LLVMValueRef) -> bool {
+        unsafe {
+            let Some(basic_block) = instruction_parent_block(instruction) else {
+                return false;
+            };
+
+            if let Some(pattern) = &self.name_pattern {
+                if !is_glob_match(pattern, &llvm_basic_block_name(&basic_block)) {
+                    return false;
+                }
+            }
+
+            match self.condition {
+                BasicBlockCondition::Any => true,
+                BasicBlockCondition::Entry => {
+                    let function = LLVMGetBasicBlockParent(basic_block);
+                    !function.is_null() && LLVMGetEntryBasicBlock(function) == basic_block
+                }
+                BasicBlockCondition::EndsWithUnreachable => {
+                    let terminator = LLVMGetBasicBlockTerminator(basic_block);
+                    !terminator.is_null()
+                        && LLVMGetInstructionOpcode(terminator) == LLVMOpcode::LLVMUnreachable
+                }
+            }
+        }
+    }
+
+    fn literal(&self) -> String {
+        match (&self.condition, &self.name_pattern) {
+            (BasicBlockCondition::Any, Some(name_pattern)) => {
+                format!("m_in_block({})", text_literal(name_pattern))
+            }
+            (BasicBlockCondition::Any, None) => "m_in_block()".to_string(),
+            (BasicBlockCondition::Entry, _) => "m_in_entry_block()".to_string(),
+            (BasicBlockCondition::EndsWithUnreachable, _) => "m_in_unreachable_block()".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils;
+
+    fn matched_blocks(matcher: &str) -> Vec<String> {
+        test_utils::matched_blocks("context.ll", matcher)
+    }
+
+    #[test]
+    fn test_in_function_matcher() {
+        assert_eq!(
+            matched_blocks("m_call('free') && m_in_function('_Z*', 'nounwind')"),
+            ["_Z7cleanupPv:entry", "_Z7cleanupPv:done"]
+        );
+        assert_eq!(
+            matched_blocks("m_call('free') && m_in_function('release')"),
+            ["release:entry"]
+        );
+        assert!(matched_blocks("m_in_function('_Z*', 'noinline')").is_empty());
+        assert!(matched_blocks("m_in_function('main')").is_empty());
+    }
+
+    #[test]
+    fn test_in_block_matchers() {
+        assert_eq!(
+            matched_blocks("m_call('free') && m_in_entry_block()"),
+            ["_Z7cleanupPv:entry", "release:entry"]
+        );
+        assert_eq!(
+            matched_blocks("m_call() && m_in_block('d*')"),
+            ["_Z7cleanupPv:done"]
+        );
+        assert!(matched_blocks("m_in_block('exit')").is_empty());
+    }
+
+    #[test]
+    fn test_in_unreachable_block_matcher() {
+        assert_eq!(
+            matched_blocks("m_in_unreachable_block()"),
+            ["_Z7cleanupPv:fail", "_Z7cleanupPv:fail"]
+        );
+        assert!(matched_blocks("m_call('free') && m_in_unreachable_block()").is_empty());
+    }
+}
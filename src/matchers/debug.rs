@@ -14,6 +14,10 @@ impl Matcher<LLVMValueRef> for DebugInfoLineMatcher {
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
         unsafe { self.line == LLVMGetDebugLocLine(*instruction) }
     }
+
+    fn literal(&self) -> String {
+        format!("m_dbg_line({})", self.line)
+    }
 }
 
 #[derive(Clone)]
@@ -26,4 +30,8 @@ impl Matcher<LLVMValueRef> for DebugInfoColumnMatcher {
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
         unsafe { self.column == LLVMGetDebugLocColumn(*instruction) }
     }
+
+    fn literal(&self) -> String {
+        format!("m_dbg_column({})", self.column)
+    }
 }
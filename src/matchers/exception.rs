@@ -1,19 +1,78 @@
+use inkwell::llvm_sys::core::LLVMGetClause;
 use inkwell::llvm_sys::core::LLVMGetInstructionOpcode;
+use inkwell::llvm_sys::core::LLVMGetNumClauses;
+use inkwell::llvm_sys::core::LLVMGetNumOperands;
+use inkwell::llvm_sys::core::LLVMGetOperand;
+use inkwell::llvm_sys::core::LLVMGetParentCatchSwitch;
+use inkwell::llvm_sys::core::LLVMIsAGlobalValue;
 use inkwell::llvm_sys::core::LLVMIsAResumeInst;
+use inkwell::llvm_sys::core::LLVMIsCleanup;
 use inkwell::llvm_sys::prelude::LLVMValueRef;
 use inkwell::llvm_sys::LLVMOpcode;
 
 use crate::matchers::matchers_helper::is_call_or_invoke_inst_with_specific_name;
+use crate::matchers::matchers_helper::llvm_value_name;
+use crate::matchers::matchers_helper::text_literal;
 
 use super::Matcher;
 
+/// Landing pad instruction matcher with optional cleanup flag and catch clauses type info names
 #[derive(Clone)]
-pub struct LandingPadInstMatcher;
+pub struct LandingPadInstMatcher {
+    pub is_cleanup: Option<bool>,
+    pub type_infos: Vec<String>,
+}
+
+impl LandingPadInstMatcher {
+    pub fn create_landingpad(is_cleanup: Option<bool>, type_infos: Vec<String>) -> Self {
+        LandingPadInstMatcher {
+            is_cleanup,
+            type_infos,
+        }
+    }
+}
 
 impl Matcher<LLVMValueRef> for LandingPadInstMatcher {
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
-        unsafe { LLVMOpcode::LLVMLandingPad == LLVMGetInstructionOpcode(*instruction) }
+        unsafe {
+            if LLVMOpcode::LLVMLandingPad != LLVMGetInstructionOpcode(*instruction) {
+                return false;
+            }
+
+            if let Some(is_cleanup) = self.is_cleanup {
+                if is_cleanup != (LLVMIsCleanup(*instruction) != 0) {
+                    return false;
+                }
+            }
+
+            if self.type_infos.is_empty() {
+                return true;
+            }
+
+            // Catch clauses are global values (type infos), filter clauses are constant arrays
+            let clauses_count = LLVMGetNumClauses(*instruction);
+            let mut clauses_names: Vec<String> = Vec::with_capacity(clauses_count as usize);
+            for i in 0..clauses_count {
+                let clause = LLVMGetClause(*instruction, i);
+                if !LLVMIsAGlobalValue(clause).is_null() {
+                    clauses_names.push(llvm_value_name(&clause));
+                }
+            }
+
+            self.type_infos
+                .iter()
+                .all(|type_info| clauses_names.contains(type_info))
+        }
+    }
+
+    fn literal(&self) -> String {
+        let mut arguments: Vec<String> = vec![];
+        if let Some(is_cleanup) = self.is_cleanup {
+            arguments.push(is_cleanup.to_string());
+        }
+        arguments.extend(self.type_infos.iter().map(|name| text_literal(name)));
+        format!("m_landingpad({})", arguments.join(", "))
     }
 }
 
@@ -25,6 +84,10 @@ impl Matcher<LLVMValueRef> for InvokeInstMatcher {
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
         unsafe { LLVMOpcode::LLVMInvoke == LLVMGetInstructionOpcode(*instruction) }
     }
+
+    fn literal(&self) -> String {
+        "m_invoke()".to_string()
+    }
 }
 
 #[derive(Clone)]
@@ -35,6 +98,10 @@ impl Matcher<LLVMValueRef> for ResumeInstMatcher {
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
         unsafe { !LLVMIsAResumeInst(*instruction).is_null() }
     }
+
+    fn literal(&self) -> String {
+        "m_resume()".to_string()
+    }
 }
 
 #[derive(Clone)]
@@ -45,6 +112,10 @@ impl Matcher<LLVMValueRef> for ThrowInstMatcher {
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
         is_call_or_invoke_inst_with_specific_name(instruction, "__cxa_throw")
     }
+
+    fn literal(&self) -> String {
+        "m_throw()".to_string()
+    }
 }
 
 #[derive(Clone)]
@@ -55,4 +126,225 @@ impl Matcher<LLVMValueRef> for RethrowInstMatcher {
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
         is_call_or_invoke_inst_with_specific_name(instruction, "__cxa_rethrow")
     }
+
+    fn literal(&self) -> String {
+        "m_rethrow()".to_string()
+    }
+}
+
+/// Catch switch instruction matcher with parent pad matcher, the parent pad is `none` for top level
+#[derive(Clone)]
+pub struct CatchSwitchInstMatcher {
+    pub parent_matcher: Box<dyn Matcher<LLVMValueRef>>,
+}
+
+impl Matcher<LLVMValueRef> for CatchSwitchInstMatcher {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn is_match(&self, instruction: &LLVMValueRef) -> bool {
+        unsafe {
+            if LLVMOpcode::LLVMCatchSwitch != LLVMGetInstructionOpcode(*instruction) {
+                return false;
+            }
+
+            let parent_pad = LLVMGetOperand(*instruction, 0);
+            self.parent_matcher.is_match(&parent_pad)
+        }
+    }
+
+    fn literal(&self) -> String {
+        format!("m_catchswitch({})", self.parent_matcher.literal())
+    }
+}
+
+/// Catch pad instruction matcher with parent catch switch matcher
+#[derive(Clone)]
+pub struct CatchPadInstMatcher {
+    pub parent_matcher: Box<dyn Matcher<LLVMValueRef>>,
+}
+
+impl Matcher<LLVMValueRef> for CatchPadInstMatcher {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn is_match(&self, instruction: &LLVMValueRef) -> bool {
+        unsafe {
+            if LLVMOpcode::LLVMCatchPad != LLVMGetInstructionOpcode(*instruction) {
+                return false;
+            }
+
+            let catch_switch = LLVMGetParentCatchSwitch(*instruction);
+            self.parent_matcher.is_match(&catch_switch)
+        }
+    }
+
+    fn literal(&self) -> String {
+        format!("m_catchpad({})", self.parent_matcher.literal())
+    }
+}
+
+/// Cleanup pad instruction matcher with parent pad matcher, the parent pad is `none` for top level
+#[derive(Clone)]
+pub struct CleanupPadInstMatcher {
+    pub parent_matcher: Box<dyn Matcher<LLVMValueRef>>,
+}
+
+impl Matcher<LLVMValueRef> for CleanupPadInstMatcher {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn is_match(&self, instruction: &LLVMValueRef) -> bool {
+        unsafe {
+            if LLVMOpcode::LLVMCleanupPad != LLVMGetInstructionOpcode(*instruction) {
+                return false;
+            }
+
+            // The parent pad is always the last operand of funclet pad instruction
+            let operands_count = LLVMGetNumOperands(*instruction);
+            let parent_pad = LLVMGetOperand(*instruction, (operands_count - 1) as u32);
+            self.parent_matcher.is_match(&parent_pad)
+        }
+    }
+
+    fn literal(&self) -> String {
+        format!("m_cleanuppad({})", self.parent_matcher.literal())
+    }
+}
+
+/// Catch return instruction matcher with matcher for the catch pad it returns from
+#[derive(Clone)]
+pub struct CatchReturnInstMatcher {
+    pub pad_matcher: Box<dyn Matcher<LLVMValueRef>>,
+}
+
+impl Matcher<LLVMValueRef> for CatchReturnInstMatcher {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn is_match(&self, instruction: &LLVMValueRef) -> bool {
+        unsafe {
+            if LLVMOpcode::LLVMCatchRet != LLVMGetInstructionOpcode(*instruction) {
+                return false;
+            }
+
+            let catch_pad = LLVMGetOperand(*instruction, 0);
+            self.pad_matcher.is_match(&catch_pad)
+        }
+    }
+
+    fn literal(&self) -> String {
+        format!("m_catchret({})", self.pad_matcher.literal())
+    }
+}
+
+/// Cleanup return instruction matcher with matcher for the cleanup pad it returns from
+#[derive(Clone)]
+pub struct CleanupReturnInstMatcher {
+    pub pad_matcher: Box<dyn Matcher<LLVMValueRef>>,
+}
+
+impl Matcher<LLVMValueRef> for CleanupReturnInstMatcher {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn is_match(&self, instruction: &LLVMValueRef) -> bool {
+        unsafe {
+            if LLVMOpcode::LLVMCleanupRet != LLVMGetInstructionOpcode(*instruction) {
+                return false;
+            }
+
+            let cleanup_pad = LLVMGetOperand(*instruction, 0);
+            self.pad_matcher.is_match(&cleanup_pad)
+        }
+    }
+
+    fn literal(&self) -> String {
+        format!("m_cleanupret({})", self.pad_matcher.literal())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils;
+
+    fn matched_blocks(matcher: &str) -> Vec<String> {
+        test_utils::matched_blocks("exceptions.ll", matcher)
+    }
+
+    #[test]
+    fn test_catchswitch_matcher() {
+        assert_eq!(
+            matched_blocks("m_catchswitch()"),
+            ["funclets:dispatch", "funclets:inner_dispatch"]
+        );
+
+        // The parent pad is the first operand, `none` is not matched by pad matchers
+        assert_eq!(
+            matched_blocks("m_catchswitch(m_catchpad())"),
+            ["funclets:inner_dispatch"]
+        );
+        assert!(matched_blocks("m_catchswitch(m_cleanuppad())").is_empty());
+    }
+
+    #[test]
+    fn test_catchpad_matcher() {
+        assert_eq!(
+            matched_blocks("m_catchpad()"),
+            ["funclets:handler", "funclets:inner_handler"]
+        );
+
+        // The parent is the catch switch, not the last operand of the pad
+        assert_eq!(
+            matched_blocks("m_catchpad(m_catchswitch(m_catchpad()))"),
+            ["funclets:inner_handler"]
+        );
+        assert!(matched_blocks("m_catchpad(m_catchpad())").is_empty());
+    }
+
+    #[test]
+    fn test_cleanuppad_matcher() {
+        assert_eq!(
+            matched_blocks("m_cleanuppad()"),
+            ["funclets:inner_cleanup", "top_cleanup:cleanup_block"]
+        );
+
+        // The parent pad is the last operand after the pad arguments
+        assert_eq!(
+            matched_blocks("m_cleanuppad(m_catchpad())"),
+            ["funclets:inner_cleanup"]
+        );
+        assert!(matched_blocks("m_cleanuppad(m_catchswitch())").is_empty());
+    }
+
+    #[test]
+    fn test_funclet_return_matchers() {
+        assert_eq!(
+            matched_blocks("m_catchret()"),
+            ["funclets:handler_exit", "funclets:inner_handler"]
+        );
+        assert_eq!(
+            matched_blocks("m_catchret(m_catchpad(m_catchswitch(m_catchpad())))"),
+            ["funclets:inner_handler"]
+        );
+        assert!(matched_blocks("m_catchret(m_cleanuppad())").is_empty());
+
+        assert_eq!(
+            matched_blocks("m_cleanupret()"),
+            ["funclets:inner_cleanup", "top_cleanup:cleanup_block"]
+        );
+        assert_eq!(
+            matched_blocks("m_cleanupret(m_cleanuppad(m_catchpad()))"),
+            ["funclets:inner_cleanup"]
+        );
+        assert!(matched_blocks("m_cleanupret(m_catchpad())").is_empty());
+    }
+
+    #[test]
+    fn test_landingpad_matcher() {
+        assert_eq!(
+            matched_blocks("m_landingpad()"),
+            ["landing_pads:catch_lpad", "landing_pads:cleanup_lpad"]
+        );
+        assert_eq!(
+            matched_blocks("m_landingpad(true)"),
+            ["landing_pads:cleanup_lpad"]
+        );
+        assert_eq!(
+            matched_blocks("m_landingpad(false, '_ZTIi')"),
+            ["landing_pads:catch_lpad"]
+        );
+        assert!(matched_blocks("m_landingpad(false, '_ZTIc')").is_empty());
+        assert!(matched_blocks("m_landingpad(true, '_ZTIi')").is_empty());
+    }
 }
@@ -379,4 +379,34 @@ impl Matcher<LLVMValueRef> for FloatComparisonInstMatcher {
             false
         }
     }
+
+    fn literal(&self) -> String {
+        let prefix = if self.commutatively { "m_c_" } else { "m_" };
+        format!(
+            "{prefix}{}({}, {})",
+            real_predicate_name(&self.predicate),
+            self.lhs_matcher.literal(),
+            self.rhs_matcher.literal()
+        )
+    }
+}
+
+fn real_predicate_name(predicate: &LLVMRealPredicate) -> &'static str {
+    match predicate {
+        LLVMRealPredicate::LLVMRealOEQ => "fcmp_eq",
+        LLVMRealPredicate::LLVMRealONE => "fcmp_ne",
+        LLVMRealPredicate::LLVMRealOGT => "fcmp_gt",
+        LLVMRealPredicate::LLVMRealOGE => "fcmp_ge",
+        LLVMRealPredicate::LLVMRealOLT => "fcmp_lt",
+        LLVMRealPredicate::LLVMRealOLE => "fcmp_le",
+        LLVMRealPredicate::LLVMRealORD => "fcmp_ord",
+        LLVMRealPredicate::LLVMRealUNO => "fcmp_uno",
+        LLVMRealPredicate::LLVMRealUEQ => "fcmp_ueq",
+        LLVMRealPredicate::LLVMRealUNE => "fcmp_une",
+        LLVMRealPredicate::LLVMRealUGT => "fcmp_ugt",
+        LLVMRealPredicate::LLVMRealUGE => "fcmp_uge",
+        LLVMRealPredicate::LLVMRealULT => "fcmp_ult",
+        LLVMRealPredicate::LLVMRealULE => "fcmp_ule",
+        _ => "fcmp",
+    }
 }
@@ -0,0 +1,310 @@
+use inkwell::llvm_sys::core::LLVMCountBasicBlocks;
+use inkwell::llvm_sys::core::LLVMCountParams;
+use inkwell::llvm_sys::core::LLVMGetFirstBasicBlock;
+use inkwell::llvm_sys::core::LLVMGetFirstInstruction;
+use inkwell::llvm_sys::core::LLVMGetLinkage;
+use inkwell::llvm_sys::core::LLVMGetNextBasicBlock;
+use inkwell::llvm_sys::core::LLVMGetNextInstruction;
+use inkwell::llvm_sys::core::LLVMGetParam;
+use inkwell::llvm_sys::core::LLVMIsAFunction;
+use inkwell::llvm_sys::core::LLVMIsDeclaration;
+use inkwell::llvm_sys::core::LLVMTypeOf;
+use inkwell::llvm_sys::prelude::LLVMTypeRef;
+use inkwell::llvm_sys::prelude::LLVMValueRef;
+use inkwell::llvm_sys::LLVMLinkage;
+
+use crate::matchers::matchers_helper::function_has_attribute;
+use crate::matchers::matchers_helper::is_glob_match;
+use crate::matchers::matchers_helper::llvm_value_name;
+use crate::matchers::matchers_helper::text_literal;
+
+use super::Matcher;
+
+/// LLVM function that function matchers match, unlike `LLVMValueRef` it can't be an instruction
+/// or other value, so function and instruction matchers can't be mixed
+#[derive(Clone, Copy)]
+pub struct LLVMFunction {
+    value: LLVMValueRef,
+}
+
+impl LLVMFunction {
+    /// Return the function or `None` if the value is not a function
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn new(value: LLVMValueRef) -> Option<Self> {
+        let is_function = unsafe { !LLVMIsAFunction(value).is_null() };
+        is_function.then_some(LLVMFunction { value })
+    }
+
+    /// Return the LLVM value of the function
+    pub fn as_value_ref(&self) -> LLVMValueRef {
+        self.value
+    }
+}
+
+/// Return the linkage name as written in LLVM IR, `external` for the default linkage
+fn linkage_name(linkage: LLVMLinkage) -> &'static str {
+    match linkage {
+        LLVMLinkage::LLVMExternalLinkage => "external",
+        LLVMLinkage::LLVMAvailableExternallyLinkage => "available_externally",
+        LLVMLinkage::LLVMLinkOnceAnyLinkage => "linkonce",
+        LLVMLinkage::LLVMLinkOnceODRLinkage => "linkonce_odr",
+        LLVMLinkage::LLVMWeakAnyLinkage => "weak",
+        LLVMLinkage::LLVMWeakODRLinkage => "weak_odr",
+        LLVMLinkage::LLVMAppendingLinkage => "appending",
+        LLVMLinkage::LLVMInternalLinkage => "internal",
+        LLVMLinkage::LLVMPrivateLinkage => "private",
+        LLVMLinkage::LLVMExternalWeakLinkage => "extern_weak",
+        LLVMLinkage::LLVMCommonLinkage => "common",
+        _ => "unknown",
+    }
+}
+
+/// Match function with optional name pattern and list of function matchers that all must match
+#[derive(Clone)]
+pub struct FunctionMatcher {
+    pub name_pattern: Option<String>,
+    pub matchers: Vec<Box<dyn Matcher<LLVMFunction>>>,
+}
+
+impl Matcher<LLVMFunction> for FunctionMatcher {
+    fn is_match(&self, function: &LLVMFunction) -> bool {
+        if let Some(pattern) = &self.name_pattern {
+            if !is_glob_match(pattern, &llvm_value_name(&function.value)) {
+                return false;
+            }
+        }
+
+        self.matchers
+            .iter()
+            .all(|matcher| matcher.is_match(function))
+    }
+
+    fn literal(&self) -> String {
+        let mut arguments: Vec<String> = vec![];
+        if let Some(name_pattern) = &self.name_pattern {
+            arguments.push(text_literal(name_pattern));
+        } else if !self.matchers.is_empty() {
+            arguments.push(text_literal("*"));
+        }
+        arguments.extend(self.matchers.iter().map(|matcher| matcher.literal()));
+        format!("m_function({})", arguments.join(", "))
+    }
+}
+
+/// Match function linkage, for example `external`, `internal` or `linkonce_odr`
+#[derive(Clone)]
+pub struct FunctionLinkageMatcher {
+    pub linkage: String,
+}
+
+impl Matcher<LLVMFunction> for FunctionLinkageMatcher {
+    fn is_match(&self, function: &LLVMFunction) -> bool {
+        let linkage = unsafe { LLVMGetLinkage(function.value) };
+        linkage_name(linkage) == self.linkage
+    }
+
+    fn literal(&self) -> String {
+        format!("m_fn_linkage({})", text_literal(&self.linkage))
+    }
+}
+
+/// Match function that has all the attributes, for example `noinline`, `optnone` or `nounwind`
+#[derive(Clone)]
+pub struct FunctionAttributesMatcher {
+    pub attributes: Vec<String>,
+}
+
+impl Matcher<LLVMFunction> for FunctionAttributesMatcher {
+    fn is_match(&self, function: &LLVMFunction) -> bool {
+        self.attributes
+            .iter()
+            .all(|attribute| function_has_attribute(&function.value, attribute))
+    }
+
+    fn literal(&self) -> String {
+        let attributes: Vec<String> = self
+            .attributes
+            .iter()
+            .map(|name| text_literal(name))
+            .collect();
+        format!("m_fn_attributes({})", attributes.join(", "))
+    }
+}
+
+/// Match function that is declared but not defined in the module
+#[derive(Clone)]
+pub struct FunctionDeclarationMatcher;
+
+impl Matcher<LLVMFunction> for FunctionDeclarationMatcher {
+    fn is_match(&self, function: &LLVMFunction) -> bool {
+        unsafe { LLVMIsDeclaration(function.value) != 0 }
+    }
+
+    fn literal(&self) -> String {
+        "m_fn_declaration()".to_string()
+    }
+}
+
+/// Match function by the number of arguments
+#[derive(Clone)]
+pub struct FunctionArgumentsCountMatcher {
+    pub count: u32,
+}
+
+impl Matcher<LLVMFunction> for FunctionArgumentsCountMatcher {
+    fn is_match(&self, function: &LLVMFunction) -> bool {
+        unsafe { LLVMCountParams(function.value) == self.count }
+    }
+
+    fn literal(&self) -> String {
+        format!("m_fn_args_count({})", self.count)
+    }
+}
+
+/// Match function by the types of arguments, one type matcher for each argument
+#[derive(Clone)]
+pub struct FunctionArgumentsTypesMatcher {
+    pub matchers: Vec<Box<dyn Matcher<LLVMTypeRef>>>,
+}
+
+impl Matcher<LLVMFunction> for FunctionArgumentsTypesMatcher {
+    fn is_match(&self, function: &LLVMFunction) -> bool {
+        unsafe {
+            if LLVMCountParams(function.value) as usize != self.matchers.len() {
+                return false;
+            }
+
+            self.matchers.iter().enumerate().all(|(index, matcher)| {
+                let parameter = LLVMGetParam(function.value, index as u32);
+                matcher.is_match(&LLVMTypeOf(parameter))
+            })
+        }
+    }
+
+    fn literal(&self) -> String {
+        let matchers: Vec<String> = self.matchers.iter().map(|m| m.literal()).collect();
+        format!("m_fn_args_types({})", matchers.join(", "))
+    }
+}
+
+/// Match function by the number of basic blocks in range from min to optional max
+#[derive(Clone)]
+pub struct FunctionBlocksCountMatcher {
+    pub min: u32,
+    pub max: Option<u32>,
+}
+
+impl Matcher<LLVMFunction> for FunctionBlocksCountMatcher {
+    fn is_match(&self, function: &LLVMFunction) -> bool {
+        let count = unsafe { LLVMCountBasicBlocks(function.value) };
+        count >= self.min && self.max.is_none_or(|max| count <= max)
+    }
+
+    fn literal(&self) -> String {
+        match self.max {
+            Some(max) => format!("m_fn_blocks_count({}, {max})", self.min),
+            None => format!("m_fn_blocks_count({})", self.min),
+        }
+    }
+}
+
+/// Match function that contains at least one instruction matching the instruction matcher
+#[derive(Clone)]
+pub struct FunctionContainsMatcher {
+    pub matcher: Box<dyn Matcher<LLVMValueRef>>,
+}
+
+impl Matcher<LLVMFunction> for FunctionContainsMatcher {
+    fn is_match(&self, function: &LLVMFunction) -> bool {
+        unsafe {
+            let mut basic_block = LLVMGetFirstBasicBlock(function.value);
+            while !basic_block.is_null() {
+                let mut instruction = LLVMGetFirstInstruction(basic_block);
+                while !instruction.is_null() {
+                    if self.matcher.is_match(&instruction) {
+                        return true;
+                    }
+                    instruction = LLVMGetNextInstruction(instruction);
+                }
+                basic_block = LLVMGetNextBasicBlock(basic_block);
+            }
+        }
+
+        false
+    }
+
+    fn literal(&self) -> String {
+        format!("m_fn_contains({})", self.matcher.literal())
+    }
+}
+
+#[derive(PartialEq, Clone)]
+enum FunctionCombineKind {
+    And,
+    Or,
+}
+
+/// Match function if both or any of the two function matchers match
+#[derive(Clone)]
+pub struct FunctionCombineMatcher {
+    lhs: Box<dyn Matcher<LLVMFunction>>,
+    rhs: Box<dyn Matcher<LLVMFunction>>,
+    kind: FunctionCombineKind,
+}
+
+impl FunctionCombineMatcher {
+    pub fn create_and(
+        lhs: Box<dyn Matcher<LLVMFunction>>,
+        rhs: Box<dyn Matcher<LLVMFunction>>,
+    ) -> Self {
+        FunctionCombineMatcher {
+            lhs,
+            rhs,
+            kind: FunctionCombineKind::And,
+        }
+    }
+
+    pub fn create_or(
+        lhs: Box<dyn Matcher<LLVMFunction>>,
+        rhs: Box<dyn Matcher<LLVMFunction>>,
+    ) -> Self {
+        FunctionCombineMatcher {
+            lhs,
+            rhs,
+            kind: FunctionCombineKind::Or,
+        }
+    }
+}
+
+impl Matcher<LLVMFunction> for FunctionCombineMatcher {
+    fn is_match(&self, function: &LLVMFunction) -> bool {
+        match self.kind {
+            FunctionCombineKind::And => self.lhs.is_match(function) && self.rhs.is_match(function),
+            FunctionCombineKind::Or => self.lhs.is_match(function) || self.rhs.is_match(function),
+        }
+    }
+
+    fn literal(&self) -> String {
+        let name = match self.kind {
+            FunctionCombineKind::And => "m_fn_and",
+            FunctionCombineKind::Or => "m_fn_or",
+        };
+        format!("{name}({}, {})", self.lhs.literal(), self.rhs.literal())
+    }
+}
+
+/// Match function if the function matcher doesn't match
+#[derive(Clone)]
+pub struct FunctionNotMatcher {
+    pub matcher: Box<dyn Matcher<LLVMFunction>>,
+}
+
+impl Matcher<LLVMFunction> for FunctionNotMatcher {
+    fn is_match(&self, function: &LLVMFunction) -> bool {
+        !self.matcher.is_match(function)
+    }
+
+    fn literal(&self) -> String {
+        format!("m_fn_not({})", self.matcher.literal())
+    }
+}
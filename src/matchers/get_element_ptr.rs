@@ -12,4 +12,8 @@ impl Matcher<LLVMValueRef> for GetElementPtrMatcher {
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
         unsafe { !LLVMIsAGetElementPtrInst(*instruction).is_null() }
     }
+
+    fn literal(&self) -> String {
+        "m_get_element_ptr()".to_string()
+    }
 }
@@ -283,4 +283,29 @@ impl Matcher<LLVMValueRef> for IntComparisonInstMatcher {
             false
         }
     }
+
+    fn literal(&self) -> String {
+        let prefix = if self.commutatively { "m_c_" } else { "m_" };
+        format!(
+            "{prefix}{}({}, {})",
+            int_predicate_name(&self.predicate),
+            self.lhs_matcher.literal(),
+            self.rhs_matcher.literal()
+        )
+    }
+}
+
+fn int_predicate_name(predicate: &LLVMIntPredicate) -> &'static str {
+    match predicate {
+        LLVMIntPredicate::LLVMIntEQ => "icmp_eq",
+        LLVMIntPredicate::LLVMIntNE => "icmp_ne",
+        LLVMIntPredicate::LLVMIntUGT => "icmp_ugt",
+        LLVMIntPredicate::LLVMIntUGE => "icmp_ueg",
+        LLVMIntPredicate::LLVMIntULT => "icmp_ult",
+        LLVMIntPredicate::LLVMIntULE => "icmp_ule",
+        LLVMIntPredicate::LLVMIntSGT => "icmp_sgt",
+        LLVMIntPredicate::LLVMIntSGE => "icmp_sge",
+        LLVMIntPredicate::LLVMIntSLT => "icmp_slt",
+        LLVMIntPredicate::LLVMIntSLE => "icmp_sle",
+    }
 }
@@ -1,7 +1,18 @@
+use std::ffi::c_char;
+use std::ffi::CStr;
+
+use inkwell::llvm_sys::core::LLVMGetBasicBlockName;
 use inkwell::llvm_sys::core::LLVMGetCalledValue;
+use inkwell::llvm_sys::core::LLVMGetEnumAttributeAtIndex;
+use inkwell::llvm_sys::core::LLVMGetEnumAttributeKindForName;
 use inkwell::llvm_sys::core::LLVMGetInstructionOpcode;
+use inkwell::llvm_sys::core::LLVMGetInstructionParent;
+use inkwell::llvm_sys::core::LLVMGetStringAttributeAtIndex;
 use inkwell::llvm_sys::core::LLVMGetValueName2;
+use inkwell::llvm_sys::core::LLVMIsAInstruction;
+use inkwell::llvm_sys::prelude::LLVMBasicBlockRef;
 use inkwell::llvm_sys::prelude::LLVMValueRef;
+use inkwell::llvm_sys::LLVMAttributeFunctionIndex;
 use inkwell::llvm_sys::LLVMOpcode;
 
 pub(crate) fn is_call_or_invoke_inst_with_specific_name(
@@ -13,13 +24,249 @@ pub(crate) fn is_call_or_invoke_inst_with_specific_name(
             LLVMGetInstructionOpcode(*instruction),
             LLVMOpcode::LLVMCall | LLVMOpcode::LLVMInvoke
         ) {
-            let mut len: usize = 0;
             let called_value = LLVMGetCalledValue(*instruction);
-            let name_ptr = LLVMGetValueName2(called_value, &mut len);
-            let name_slice = std::slice::from_raw_parts(name_ptr as *const u8, len);
-            let called_name = std::str::from_utf8_unchecked(name_slice).to_string();
-            return called_name.eq(name);
+            return llvm_value_name(&called_value).eq(name);
         }
         false
     }
 }
+
+/// Return the opcode name as written in LLVM IR, for example `add`, `icmp` or `getelementptr`
+pub(crate) fn llvm_opcode_name(opcode: LLVMOpcode) -> String {
+    let name = match opcode {
+        LLVMOpcode::LLVMRet => "ret",
+        LLVMOpcode::LLVMBr => "br",
+        LLVMOpcode::LLVMSwitch => "switch",
+        LLVMOpcode::LLVMIndirectBr => "indirectbr",
+        LLVMOpcode::LLVMInvoke => "invoke",
+        LLVMOpcode::LLVMUnreachable => "unreachable",
+        LLVMOpcode::LLVMCallBr => "callbr",
+        LLVMOpcode::LLVMFNeg => "fneg",
+        LLVMOpcode::LLVMAdd => "add",
+        LLVMOpcode::LLVMFAdd => "fadd",
+        LLVMOpcode::LLVMSub => "sub",
+        LLVMOpcode::LLVMFSub => "fsub",
+        LLVMOpcode::LLVMMul => "mul",
+        LLVMOpcode::LLVMFMul => "fmul",
+        LLVMOpcode::LLVMUDiv => "udiv",
+        LLVMOpcode::LLVMSDiv => "sdiv",
+        LLVMOpcode::LLVMFDiv => "fdiv",
+        LLVMOpcode::LLVMURem => "urem",
+        LLVMOpcode::LLVMSRem => "srem",
+        LLVMOpcode::LLVMFRem => "frem",
+        LLVMOpcode::LLVMShl => "shl",
+        LLVMOpcode::LLVMLShr => "lshr",
+        LLVMOpcode::LLVMAShr => "ashr",
+        LLVMOpcode::LLVMAnd => "and",
+        LLVMOpcode::LLVMOr => "or",
+        LLVMOpcode::LLVMXor => "xor",
+        LLVMOpcode::LLVMAlloca => "alloca",
+        LLVMOpcode::LLVMLoad => "load",
+        LLVMOpcode::LLVMStore => "store",
+        LLVMOpcode::LLVMGetElementPtr => "getelementptr",
+        LLVMOpcode::LLVMTrunc => "trunc",
+        LLVMOpcode::LLVMZExt => "zext",
+        LLVMOpcode::LLVMSExt => "sext",
+        LLVMOpcode::LLVMFPToUI => "fptoui",
+        LLVMOpcode::LLVMFPToSI => "fptosi",
+        LLVMOpcode::LLVMUIToFP => "uitofp",
+        LLVMOpcode::LLVMSIToFP => "sitofp",
+        LLVMOpcode::LLVMFPTrunc => "fptrunc",
+        LLVMOpcode::LLVMFPExt => "fpext",
+        LLVMOpcode::LLVMPtrToInt => "ptrtoint",
+        LLVMOpcode::LLVMIntToPtr => "inttoptr",
+        LLVMOpcode::LLVMBitCast => "bitcast",
+        LLVMOpcode::LLVMAddrSpaceCast => "addrspacecast",
+        LLVMOpcode::LLVMICmp => "icmp",
+        LLVMOpcode::LLVMFCmp => "fcmp",
+        LLVMOpcode::LLVMPHI => "phi",
+        LLVMOpcode::LLVMCall => "call",
+        LLVMOpcode::LLVMSelect => "select",
+        LLVMOpcode::LLVMUserOp1 => "userop1",
+        LLVMOpcode::LLVMUserOp2 => "userop2",
+        LLVMOpcode::LLVMVAArg => "va_arg",
+        LLVMOpcode::LLVMExtractElement => "extractelement",
+        LLVMOpcode::LLVMInsertElement => "insertelement",
+        LLVMOpcode::LLVMShuffleVector => "shufflevector",
+        LLVMOpcode::LLVMExtractValue => "extractvalue",
+        LLVMOpcode::LLVMInsertValue => "insertvalue",
+        LLVMOpcode::LLVMFreeze => "freeze",
+        LLVMOpcode::LLVMFence => "fence",
+        LLVMOpcode::LLVMAtomicCmpXchg => "cmpxchg",
+        LLVMOpcode::LLVMAtomicRMW => "atomicrmw",
+        LLVMOpcode::LLVMResume => "resume",
+        LLVMOpcode::LLVMLandingPad => "landingpad",
+        LLVMOpcode::LLVMCleanupRet => "cleanupret",
+        LLVMOpcode::LLVMCatchRet => "catchret",
+        LLVMOpcode::LLVMCatchPad => "catchpad",
+        LLVMOpcode::LLVMCleanupPad => "cleanuppad",
+        LLVMOpcode::LLVMCatchSwitch => "catchswitch",
+    };
+    name.to_string()
+}
+
+/// Return the name of LLVM value or empty string if it has no name
+pub(crate) fn llvm_value_name(value: &LLVMValueRef) -> String {
+    unsafe {
+        let mut len: usize = 0;
+        let name_ptr = LLVMGetValueName2(*value, &mut len);
+        if name_ptr.is_null() {
+            return String::new();
+        }
+        let name_slice = std::slice::from_raw_parts(name_ptr as *const u8, len);
+        std::str::from_utf8_unchecked(name_slice).to_string()
+    }
+}
+
+/// Return the parent basic block of LLVM value only if the value is an instruction
+pub(crate) fn instruction_parent_block(value: &LLVMValueRef) -> Option<LLVMBasicBlockRef> {
+    unsafe {
+        if LLVMIsAInstruction(*value).is_null() {
+            return None;
+        }
+
+        let basic_block = LLVMGetInstructionParent(*value);
+        if basic_block.is_null() {
+            return None;
+        }
+
+        Some(basic_block)
+    }
+}
+
+/// Return the name of LLVM basic block or empty string if it has no name
+pub(crate) fn llvm_basic_block_name(basic_block: &LLVMBasicBlockRef) -> String {
+    unsafe {
+        let name_ptr = LLVMGetBasicBlockName(*basic_block);
+        if name_ptr.is_null() {
+            return String::new();
+        }
+        CStr::from_ptr(name_ptr).to_string_lossy().to_string()
+    }
+}
+
+/// Check if LLVM function has enum or string attribute with specific name
+pub(crate) fn function_has_attribute(function: &LLVMValueRef, name: &str) -> bool {
+    unsafe {
+        let name_ptr = name.as_ptr() as *const c_char;
+        let kind = LLVMGetEnumAttributeKindForName(name_ptr, name.len());
+        if kind != 0 {
+            let attribute =
+                LLVMGetEnumAttributeAtIndex(*function, LLVMAttributeFunctionIndex, kind);
+            return !attribute.is_null();
+        }
+
+        let attribute = LLVMGetStringAttributeAtIndex(
+            *function,
+            LLVMAttributeFunctionIndex,
+            name_ptr,
+            name.len() as u32,
+        );
+        !attribute.is_null()
+    }
+}
+
+/// Return the text as a quoted string literal, used to print matchers
+///
+/// Backslashes and quotes are escaped so the literal can be parsed back as the same text
+pub(crate) fn text_literal(text: &str) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{escaped}\"")
+}
+
+/// Check if text is matching glob pattern with `*` and `?` wildcards
+pub(crate) fn is_glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let mut pattern_index = 0;
+    let mut text_index = 0;
+    let mut last_star: Option<usize> = None;
+    let mut last_star_text_index = 0;
+
+    while text_index < text.len() {
+        if pattern_index < pattern.len()
+            && (pattern[pattern_index] == '?' || pattern[pattern_index] == text[text_index])
+        {
+            pattern_index += 1;
+            text_index += 1;
+            continue;
+        }
+
+        if pattern_index < pattern.len() && pattern[pattern_index] == '*' {
+            last_star = Some(pattern_index);
+            last_star_text_index = text_index;
+            pattern_index += 1;
+            continue;
+        }
+
+        // Backtrack and let the last star consume one more character
+        if let Some(star_index) = last_star {
+            pattern_index = star_index + 1;
+            last_star_text_index += 1;
+            text_index = last_star_text_index;
+            continue;
+        }
+
+        return false;
+    }
+
+    while pattern_index < pattern.len() && pattern[pattern_index] == '*' {
+        pattern_index += 1;
+    }
+
+    pattern_index == pattern.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(is_glob_match("main", "main"));
+        assert!(!is_glob_match("main", "main2"));
+        assert!(is_glob_match("*", ""));
+        assert!(is_glob_match("*", "anything"));
+        assert!(is_glob_match("_Z*", "_Z3fooi"));
+        assert!(!is_glob_match("_Z*", "foo"));
+        assert!(is_glob_match("*.cold", "function.cold"));
+        assert!(is_glob_match("f?o", "foo"));
+        assert!(!is_glob_match("f?o", "fo"));
+        assert!(is_glob_match("a*b*c", "aXXbYYc"));
+        assert!(!is_glob_match("a*b*c", "aXXbYY"));
+    }
+
+    #[test]
+    fn test_llvm_opcode_name() {
+        assert_eq!(llvm_opcode_name(LLVMOpcode::LLVMAdd), "add");
+        assert_eq!(
+            llvm_opcode_name(LLVMOpcode::LLVMGetElementPtr),
+            "getelementptr"
+        );
+        assert_eq!(llvm_opcode_name(LLVMOpcode::LLVMAtomicCmpXchg), "cmpxchg");
+        assert_eq!(llvm_opcode_name(LLVMOpcode::LLVMAtomicRMW), "atomicrmw");
+        assert_eq!(llvm_opcode_name(LLVMOpcode::LLVMVAArg), "va_arg");
+        assert_eq!(
+            llvm_opcode_name(LLVMOpcode::LLVMAddrSpaceCast),
+            "addrspacecast"
+        );
+    }
+
+    #[test]
+    fn test_text_literal() {
+        assert_eq!(text_literal("malloc"), r#""malloc""#);
+        assert_eq!(text_literal(r#"a"b\c"#), r#""a\"b\\c""#);
+        assert_eq!(text_literal(r"C:\"), r#""C:\\""#);
+    }
+
+    #[test]
+    fn test_text_literal_round_trip() {
+        let literal = test_utils::query_column("sample.ll", r#"SELECT m_call('a"b\\c')"#);
+        assert_eq!(literal, [r#"m_call("a\"b\\c")"#]);
+
+        let query = format!("SELECT {}", literal[0]);
+        assert_eq!(test_utils::query_column("sample.ll", &query), literal);
+    }
+}
@@ -5,9 +5,11 @@ pub mod call;
 pub mod cast;
 pub mod combine;
 pub mod constants;
+pub mod context;
 pub mod debug;
 pub mod exception;
 pub mod fcmp;
+pub mod function;
 pub mod get_element_ptr;
 pub mod icmp;
 pub mod operand_bundle;
@@ -15,10 +17,13 @@ pub mod other;
 pub mod types;
 pub mod usage;
 
-mod matchers_helper;
+pub(crate) mod matchers_helper;
 
 dyn_clone::clone_trait_object!(<T> Matcher<T>);
 
 pub trait Matcher<T: Clone>: DynClone {
     fn is_match(&self, node: &T) -> bool;
+
+    /// Readable form of the matcher as the function call that build it, for example `m_add(m_zero(), m_any_inst())`
+    fn literal(&self) -> String;
 }
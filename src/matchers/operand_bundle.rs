@@ -6,6 +6,8 @@ use inkwell::llvm_sys::core::LLVMGetOperandBundleAtIndex;
 use inkwell::llvm_sys::core::LLVMGetOperandBundleTag;
 use inkwell::llvm_sys::prelude::LLVMValueRef;
 
+use crate::matchers::matchers_helper::text_literal;
+
 use super::Matcher;
 
 #[derive(Clone)]
@@ -48,4 +50,8 @@ impl Matcher<LLVMValueRef> for OperandBundleNameMatcher {
             false
         }
     }
+
+    fn literal(&self) -> String {
+        format!("m_operand_bundle({})", text_literal(&self.name))
+    }
 }
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::ffi::CStr;
 
 use inkwell::llvm_sys::core::LLVMGetIndices;
@@ -7,12 +8,16 @@ use inkwell::llvm_sys::core::LLVMGetNumOperands;
 use inkwell::llvm_sys::core::LLVMGetOperand;
 use inkwell::llvm_sys::core::LLVMGetValueKind;
 use inkwell::llvm_sys::core::LLVMGetValueName2;
+use inkwell::llvm_sys::core::LLVMIsAGlobalValue;
+use inkwell::llvm_sys::core::LLVMIsAUser;
 use inkwell::llvm_sys::core::LLVMTypeOf;
 use inkwell::llvm_sys::prelude::LLVMTypeRef;
 use inkwell::llvm_sys::prelude::LLVMValueRef;
 use inkwell::llvm_sys::LLVMOpcode;
 use inkwell::llvm_sys::LLVMValueKind;
 
+use crate::matchers::matchers_helper::text_literal;
+
 use super::Matcher;
 
 /// Instruction Matcher to match to any instruction, used mostly as default matcher
@@ -23,6 +28,10 @@ impl Matcher<LLVMValueRef> for AnyInstMatcher {
     fn is_match(&self, _instruction: &LLVMValueRef) -> bool {
         true
     }
+
+    fn literal(&self) -> String {
+        "m_any_inst()".to_string()
+    }
 }
 
 #[derive(Clone)]
@@ -38,6 +47,10 @@ impl Matcher<LLVMValueRef> for InstTypeMatcher {
             self.matcher.is_match(&value_type)
         }
     }
+
+    fn literal(&self) -> String {
+        format!("m_inst_type({})", self.matcher.literal())
+    }
 }
 
 #[derive(Clone)]
@@ -77,6 +90,20 @@ impl Matcher<LLVMValueRef> for ExtractValueInstMatcher {
             true
         }
     }
+
+    fn literal(&self) -> String {
+        match &self.indices {
+            Some(indices) => {
+                let indices: Vec<String> = indices.iter().map(|i| i.to_string()).collect();
+                format!(
+                    "m_extract_value({}, [{}])",
+                    self.matcher.literal(),
+                    indices.join(", ")
+                )
+            }
+            None => format!("m_extract_value({})", self.matcher.literal()),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -90,6 +117,10 @@ impl Matcher<LLVMValueRef> for PoisonValueMatcher {
             value_kind == LLVMValueKind::LLVMPoisonValueKind
         }
     }
+
+    fn literal(&self) -> String {
+        "m_poison()".to_string()
+    }
 }
 
 /// Return instruction matcher to check if current value is function argument with optional name
@@ -124,6 +155,24 @@ impl Matcher<LLVMValueRef> for ArgumentMatcher {
             false
         }
     }
+
+    fn literal(&self) -> String {
+        let name = self.name.as_deref().map(text_literal);
+        match (name, &self.type_matcher) {
+            (Some(name), Some(type_matcher)) => {
+                format!("m_argument({name}, {})", type_matcher.literal())
+            }
+            (None, Some(type_matcher)) => {
+                format!(
+                    "m_argument({}, {})",
+                    text_literal(""),
+                    type_matcher.literal()
+                )
+            }
+            (Some(name), None) => format!("m_argument({name})"),
+            (None, None) => "m_argument()".to_string(),
+        }
+    }
 }
 
 /// Return instruction matcher to check if current instruction is return instruction with optional specific name or not
@@ -150,6 +199,13 @@ impl Matcher<LLVMValueRef> for LabelInstMatcher {
             false
         }
     }
+
+    fn literal(&self) -> String {
+        match &self.name {
+            Some(name) => format!("m_label({})", text_literal(name)),
+            None => "m_label()".to_string(),
+        }
+    }
 }
 
 /// Return instruction matcher to check if current instruction is return instruction with specific type or not
@@ -170,6 +226,10 @@ impl Matcher<LLVMValueRef> for ReturnInstMatcher {
             false
         }
     }
+
+    fn literal(&self) -> String {
+        format!("m_return({})", self.matcher.literal())
+    }
 }
 
 /// Unreachable instruction matcher to check if current instruction is Unreachable instruction
@@ -181,6 +241,10 @@ impl Matcher<LLVMValueRef> for UnreachableInstMatcher {
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
         unsafe { LLVMGetInstructionOpcode(*instruction) == LLVMOpcode::LLVMUnreachable }
     }
+
+    fn literal(&self) -> String {
+        "m_unreachable()".to_string()
+    }
 }
 
 /// Match the number of operands in LLVM instruction
@@ -200,4 +264,221 @@ impl Matcher<LLVMValueRef> for OperandCountMatcher {
     fn is_match(&self, instruction: &LLVMValueRef) -> bool {
         unsafe { LLVMGetNumOperands(*instruction) == self.expected_number }
     }
+
+    fn literal(&self) -> String {
+        format!("m_operands_number({})", self.expected_number)
+    }
+}
+
+/// Return the operands of LLVM value or empty list if it's not a user
+fn value_operands(value: &LLVMValueRef) -> Vec<LLVMValueRef> {
+    unsafe {
+        if LLVMIsAUser(*value).is_null() {
+            return vec![];
+        }
+
+        let operands_count = LLVMGetNumOperands(*value).max(0) as u32;
+        (0..operands_count)
+            .map(|i| LLVMGetOperand(*value, i))
+            .collect()
+    }
+}
+
+/// Match the operand with specific index in LLVM instruction
+#[derive(Clone)]
+pub struct OperandMatcher {
+    pub index: usize,
+    pub matcher: Box<dyn Matcher<LLVMValueRef>>,
+}
+
+impl Matcher<LLVMValueRef> for OperandMatcher {
+    fn is_match(&self, instruction: &LLVMValueRef) -> bool {
+        match value_operands(instruction).get(self.index) {
+            Some(operand) => self.matcher.is_match(operand),
+            None => false,
+        }
+    }
+
+    fn literal(&self) -> String {
+        format!("m_operand({}, {})", self.index, self.matcher.literal())
+    }
+}
+
+#[derive(Clone)]
+enum OperandsMatcherKind {
+    Any,
+    All,
+}
+
+/// Match any or all operands of LLVM instruction
+#[derive(Clone)]
+pub struct OperandsMatcher {
+    matcher: Box<dyn Matcher<LLVMValueRef>>,
+    kind: OperandsMatcherKind,
+}
+
+impl OperandsMatcher {
+    pub fn create_any_operand(matcher: Box<dyn Matcher<LLVMValueRef>>) -> Self {
+        OperandsMatcher {
+            matcher,
+            kind: OperandsMatcherKind::Any,
+        }
+    }
+
+    pub fn create_all_operands(matcher: Box<dyn Matcher<LLVMValueRef>>) -> Self {
+        OperandsMatcher {
+            matcher,
+            kind: OperandsMatcherKind::All,
+        }
+    }
+}
+
+impl Matcher<LLVMValueRef> for OperandsMatcher {
+    fn is_match(&self, instruction: &LLVMValueRef) -> bool {
+        let operands = value_operands(instruction);
+        match self.kind {
+            OperandsMatcherKind::Any => operands.iter().any(|op| self.matcher.is_match(op)),
+            OperandsMatcherKind::All => operands.iter().all(|op| self.matcher.is_match(op)),
+        }
+    }
+
+    fn literal(&self) -> String {
+        match self.kind {
+            OperandsMatcherKind::Any => format!("m_any_operand({})", self.matcher.literal()),
+            OperandsMatcherKind::All => format!("m_all_operands({})", self.matcher.literal()),
+        }
+    }
+}
+
+/// Match if any value in the operands DAG of LLVM instruction up to specific depth is matching,
+/// The search doesn't walk into global values operands
+#[derive(Clone)]
+pub struct ContainsMatcher {
+    pub matcher: Box<dyn Matcher<LLVMValueRef>>,
+    pub depth: usize,
+}
+
+impl Matcher<LLVMValueRef> for ContainsMatcher {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn is_match(&self, instruction: &LLVMValueRef) -> bool {
+        let mut visited: HashSet<LLVMValueRef> = HashSet::new();
+        visited.insert(*instruction);
+
+        let mut current_level = vec![*instruction];
+        for _ in 0..self.depth {
+            let mut next_level: Vec<LLVMValueRef> = vec![];
+            for value in current_level.iter() {
+                unsafe {
+                    if value != instruction && !LLVMIsAGlobalValue(*value).is_null() {
+                        continue;
+                    }
+                }
+
+                for operand in value_operands(value) {
+                    if !visited.insert(operand) {
+                        continue;
+                    }
+
+                    if self.matcher.is_match(&operand) {
+                        return true;
+                    }
+
+                    next_level.push(operand);
+                }
+            }
+
+            if next_level.is_empty() {
+                break;
+            }
+
+            current_level = next_level;
+        }
+
+        false
+    }
+
+    fn literal(&self) -> String {
+        format!("m_contains({}, {})", self.matcher.literal(), self.depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::query_column;
+
+    fn matched_instructions(matcher: &str) -> Vec<String> {
+        let query =
+            format!("SELECT instruction FROM instructions WHERE m_inst(instruction, {matcher})");
+        query_column("sample.ll", &query)
+            .iter()
+            .map(|instruction| instruction.trim().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_operands_matchers() {
+        assert_eq!(
+            matched_instructions("m_any_operand(m_argument())"),
+            [
+                "%mul = mul i32 %a, 3",
+                "%div = sdiv i32 %a, %b",
+                "%done = icmp eq i32 %next, %n"
+            ]
+        );
+        assert_eq!(
+            matched_instructions("m_all_operands(m_argument())"),
+            ["%div = sdiv i32 %a, %b"]
+        );
+    }
+
+    #[test]
+    fn test_contains_matcher_depth() {
+        // The root instruction is not matched, only values found at most `depth` levels away
+        assert_eq!(
+            matched_instructions("m_contains(m_mul(), 1)"),
+            ["%add = add i32 %mul, 0"]
+        );
+        assert_eq!(
+            matched_instructions("m_contains(m_mul(), 2)"),
+            ["%add = add i32 %mul, 0", "ret i32 %add"]
+        );
+        assert_eq!(
+            matched_instructions("m_all_operands(m_contains(m_argument(), 1))"),
+            ["ret i32 %div"]
+        );
+    }
+
+    #[test]
+    fn test_contains_matcher_phi_cycle() {
+        // `%next` and `%i` use each other, the search must stop when the values are visited
+        assert_eq!(
+            matched_instructions("m_contains(m_zero(), 3)"),
+            [
+                "%add = add i32 %mul, 0",
+                "ret i32 %add",
+                "%i = phi i32 [ 0, %entry ], [ %next, %loop ]",
+                "%next = add i32 %i, 1",
+                "%done = icmp eq i32 %next, %n"
+            ]
+        );
+        assert!(matched_instructions("m_contains(m_mul(), 100)")
+            .iter()
+            .all(|instruction| !instruction.contains("%next")));
+    }
+
+    #[test]
+    fn test_contains_matcher_skip_global_values() {
+        // `@counter` initializer is zero, but the search doesn't walk into global values
+        assert_eq!(
+            matched_instructions("m_contains(m_zero(), 5)"),
+            [
+                "%add = add i32 %mul, 0",
+                "ret i32 %add",
+                "%i = phi i32 [ 0, %entry ], [ %next, %loop ]",
+                "%next = add i32 %i, 1",
+                "%done = icmp eq i32 %next, %n",
+                "br i1 %done, label %exit, label %loop"
+            ]
+        );
+    }
 }
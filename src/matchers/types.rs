@@ -17,6 +17,10 @@ impl Matcher<LLVMTypeRef> for AnyTypeMatcher {
     fn is_match(&self, _llvm_type: &LLVMTypeRef) -> bool {
         true
     }
+
+    fn literal(&self) -> String {
+        "m_any_type()".to_string()
+    }
 }
 
 /// Void Type Matcher used to match against any LLVM Void Type
@@ -31,6 +35,10 @@ impl Matcher<LLVMTypeRef> for VoidTypeMatcher {
             kind == LLVMTypeKind::LLVMVoidTypeKind
         }
     }
+
+    fn literal(&self) -> String {
+        "m_void()".to_string()
+    }
 }
 
 /// Int Type Matcher used to match against LLVM Integer Type with specific size
@@ -67,6 +75,17 @@ impl Matcher<LLVMTypeRef> for IntTypeMatcher {
         }
         false
     }
+
+    fn literal(&self) -> String {
+        match self.size {
+            IntTypeSize::Size1 => "m_int1()",
+            IntTypeSize::Size8 => "m_int8()",
+            IntTypeSize::Size16 => "m_int16()",
+            IntTypeSize::Size32 => "m_int32()",
+            IntTypeSize::Size64 => "m_int64()",
+        }
+        .to_string()
+    }
 }
 
 /// Variant of available float type sizes
@@ -93,6 +112,14 @@ impl Matcher<LLVMTypeRef> for FloatTypeMatcher {
             }
         }
     }
+
+    fn literal(&self) -> String {
+        match self.size {
+            FloatTypeSize::Size32 => "m_f32()",
+            FloatTypeSize::Size64 => "m_f64()",
+        }
+        .to_string()
+    }
 }
 
 /// Ha;f Type Matcher used to match against LLVM Half Type
@@ -104,6 +131,10 @@ impl Matcher<LLVMTypeRef> for HalfTypeMatcher {
     fn is_match(&self, llvm_type: &LLVMTypeRef) -> bool {
         unsafe { LLVMGetTypeKind(*llvm_type) == LLVMTypeKind::LLVMHalfTypeKind }
     }
+
+    fn literal(&self) -> String {
+        "m_half()".to_string()
+    }
 }
 
 /// Pointer Type Matcher used to match against LLVM Pointer Type
@@ -119,6 +150,10 @@ impl Matcher<LLVMTypeRef> for PointerTypeMatcher {
             kind == LLVMTypeKind::LLVMPointerTypeKind
         }
     }
+
+    fn literal(&self) -> String {
+        "m_ptr()".to_string()
+    }
 }
 
 /// Array Type Matcher used to match against LLVM Array Type with specific base element type and size
@@ -152,6 +187,13 @@ impl Matcher<LLVMTypeRef> for ArrayTypeMatcher {
         }
         false
     }
+
+    fn literal(&self) -> String {
+        match self.length {
+            Some(length) => format!("m_array({}, {length})", self.base_matcher.literal()),
+            None => format!("m_array({})", self.base_matcher.literal()),
+        }
+    }
 }
 
 /// Vector Type Matcher used to match against LLVM Vector Type with specific base element type and size
@@ -183,6 +225,13 @@ impl Matcher<LLVMTypeRef> for VectorTypeMatcher {
         }
         false
     }
+
+    fn literal(&self) -> String {
+        match self.length {
+            Some(length) => format!("m_vector({}, {length})", self.base_matcher.literal()),
+            None => format!("m_vector({})", self.base_matcher.literal()),
+        }
+    }
 }
 
 /// Scalable Vector Type Matcher used to match against LLVM scalable Vector Type
@@ -197,6 +246,10 @@ impl Matcher<LLVMTypeRef> for ScalableVectorTypeMatcher {
             kind == LLVMTypeKind::LLVMScalableVectorTypeKind
         }
     }
+
+    fn literal(&self) -> String {
+        "m_scalable_vector()".to_string()
+    }
 }
 
 #[cfg(test)]
@@ -447,4 +500,25 @@ mod tests {
         assert!(sized_i64_vec_matcher.is_match(&i64t_vec_10_n));
         assert!(!sized_i64_vec_matcher.is_match(&i64t_vec_20_n));
     }
+
+    #[test]
+    fn test_type_matchers_literal() {
+        let i32_matcher = Box::new(IntTypeMatcher {
+            size: IntTypeSize::Size32,
+        });
+
+        let sized_array_matcher = ArrayTypeMatcher {
+            base_matcher: i32_matcher.clone(),
+            length: Some(10),
+        };
+
+        let un_sized_vec_matcher = VectorTypeMatcher {
+            base_matcher: i32_matcher,
+            length: None,
+        };
+
+        assert_eq!(PointerTypeMatcher.literal(), "m_ptr()");
+        assert_eq!(sized_array_matcher.literal(), "m_array(m_int32(), 10)");
+        assert_eq!(un_sized_vec_matcher.literal(), "m_vector(m_int32())");
+    }
 }
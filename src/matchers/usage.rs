@@ -57,4 +57,12 @@ impl Matcher<LLVMValueRef> for UsageInstMatcher {
             self.count == number_of_usage
         }
     }
+
+    fn literal(&self) -> String {
+        match self.count {
+            0 => format!("m_unused({})", self.matcher.literal()),
+            1 => format!("m_has_one_use({})", self.matcher.literal()),
+            n => format!("m_has_n_uses({}, {n})", self.matcher.literal()),
+        }
+    }
 }
@@ -0,0 +1,262 @@
+use gitql_parser::diagnostic::Diagnostic;
+use gitql_parser::token::SourceLocation;
+use gitql_parser::token::Token;
+use gitql_parser::token::TokenKind;
+use inkwell::llvm_sys::prelude::LLVMValueRef;
+
+use crate::functions::llvm_ir_function_signatures;
+use crate::matchers::Matcher;
+
+use parser::PatternParser;
+use tokenizer::tokenize_pattern;
+
+pub mod parser;
+pub mod tokenizer;
+
+/// Error in pattern source with the span of characters that caused it
+#[derive(Debug, PartialEq)]
+pub struct PatternError {
+    pub message: String,
+    pub help: Option<String>,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl PatternError {
+    pub fn new(message: &str, start: usize, end: usize) -> Self {
+        PatternError {
+            message: message.to_string(),
+            help: None,
+            start,
+            end,
+        }
+    }
+
+    pub fn with_help(mut self, help: &str) -> Self {
+        self.help = Some(help.to_string());
+        self
+    }
+}
+
+/// Compile textual pattern like `add(mul(_, #c), 0)` into instruction matcher
+pub fn compile_pattern(source: &str) -> Result<Box<dyn Matcher<LLVMValueRef>>, PatternError> {
+    let tokens = tokenize_pattern(source)?;
+    let signatures = llvm_ir_function_signatures();
+    let source_len = source.chars().count();
+    let mut parser = PatternParser::new(&tokens, source_len, &signatures);
+    parser.parse()
+}
+
+/// Compile the text literal of each `m_pattern` call before the query is parsed, so invalid
+/// pattern fails the query with diagnostic that points to the error inside the query
+pub fn validate_patterns(query: &str, tokens: &[Token]) -> Result<(), Box<Diagnostic>> {
+    for (index, token) in tokens.iter().enumerate() {
+        let is_pattern_call = matches!(&token.kind, TokenKind::Symbol(name) if name == "m_pattern")
+            && tokens
+                .get(index + 1)
+                .is_some_and(|token| token.kind == TokenKind::LeftParen);
+        if !is_pattern_call {
+            continue;
+        }
+
+        let argument = tokens.get(index + 2).unwrap_or(token);
+        let is_closed = tokens
+            .get(index + 3)
+            .is_some_and(|token| token.kind == TokenKind::RightParen);
+        let source = match &argument.kind {
+            TokenKind::String(source) if is_closed => source,
+            _ => {
+                return Err(
+                    Diagnostic::error("`m_pattern` expects a text literal pattern")
+                        .add_help(
+                            "Write the pattern as string, for example m_pattern(\"add(_, 0)\")",
+                        )
+                        .with_location(argument.location)
+                        .as_boxed(),
+                );
+            }
+        };
+
+        if let Err(error) = compile_pattern(source) {
+            let offsets = raw_offsets(query, argument, source);
+            let location = argument.location;
+            let column = |offset: usize| {
+                // One based column of the pattern character, the first one is after the quote
+                let raw_offset = offsets[offset.min(offsets.len() - 1)];
+                location.column_start + 2 + raw_offset as u32
+            };
+
+            let column_start = column(error.start);
+            let mut diagnostic = Diagnostic::error(&format!("Invalid pattern, {}", error.message))
+                .with_location(SourceLocation {
+                    line_start: location.line_start,
+                    line_end: location.line_start,
+                    column_start,
+                    column_end: column(error.end).max(column_start + 1),
+                });
+
+            if let Some(help) = &error.help {
+                diagnostic = diagnostic.add_help(help);
+            }
+
+            return Err(diagnostic.as_boxed());
+        }
+    }
+    Ok(())
+}
+
+/// Offset of each pattern character inside the raw string literal, and the offset of the
+/// literal end, escaped characters like `\'` take two characters in the query
+fn raw_offsets(query: &str, token: &Token, source: &str) -> Vec<usize> {
+    let source_len = source.chars().count();
+    let literal: Vec<char> = query
+        .split('\n')
+        .nth(token.location.line_start.saturating_sub(1) as usize)
+        .unwrap_or_default()
+        .chars()
+        .skip(token.location.column_start as usize)
+        .collect();
+
+    let mut offsets = Vec::with_capacity(source_len + 1);
+    let mut decoded = String::new();
+    if let Some(&quote) = literal
+        .first()
+        .filter(|quote| **quote == '\'' || **quote == '"')
+    {
+        let mut index = 1;
+        while index < literal.len() && literal[index] != quote {
+            offsets.push(index - 1);
+            let is_escape = literal[index] == '\\'
+                && literal
+                    .get(index + 1)
+                    .is_some_and(|next| matches!(next, '\'' | '"' | '\\' | 'n' | 'r' | 't'));
+            if is_escape {
+                decoded.push(match literal[index + 1] {
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    character => character,
+                });
+                index += 2;
+            } else {
+                decoded.push(literal[index]);
+                index += 1;
+            }
+        }
+        offsets.push(index - 1);
+    }
+
+    // Fallback to one character per pattern character if the literal is not found in the query
+    if decoded != source {
+        return (0..=source_len).collect();
+    }
+    offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use gitql_parser::tokenizer::Tokenizer;
+
+    use super::*;
+
+    #[test]
+    fn test_compile_valid_patterns() {
+        assert!(compile_pattern("_").is_ok());
+        assert!(compile_pattern("add(mul(_, #c), 0)").is_ok());
+        assert!(compile_pattern("c_add(sub, mul) | xor(_, -1)").is_ok());
+        assert!(compile_pattern("!unreachable & call('free')").is_ok());
+        assert!(compile_pattern("trunc(binop(_, zext | sext))").is_ok());
+        assert!(compile_pattern("argument('a', vector(int32, 4))").is_ok());
+        assert!(compile_pattern("has_n_uses(add, 2)").is_ok());
+        assert!(compile_pattern("m_add(m_any_inst(), m_zero())").is_ok());
+    }
+
+    #[test]
+    fn test_compile_invalid_patterns() {
+        let error = compile_pattern("").err().unwrap();
+        assert_eq!(error.message, "Pattern can't be empty");
+
+        let error = compile_pattern("add(foo, 0)").err().unwrap();
+        assert_eq!(error.message, "Unknown matcher `foo`");
+        assert_eq!((error.start, error.end), (4, 7));
+
+        let error = compile_pattern("add(_, 0").err().unwrap();
+        assert_eq!((error.start, error.end), (8, 8));
+
+        let error = compile_pattern("add(_, #q)").err().unwrap();
+        assert_eq!(error.message, "Unknown constant class `#q`");
+        assert_eq!((error.start, error.end), (7, 9));
+
+        let error = compile_pattern("argument('a', add)").err().unwrap();
+        assert_eq!(error.message, "`add` is not a type matcher");
+
+        let error = compile_pattern("int32").err().unwrap();
+        assert_eq!(error.message, "`int32` is not an instruction matcher");
+
+        let error = compile_pattern("has_n_uses(add)").err().unwrap();
+        assert_eq!(error.message, "`has_n_uses` expects at least 2 arguments");
+
+        let error = compile_pattern("add(_, _, _)").err().unwrap();
+        assert_eq!(error.message, "`add` expects at most 2 arguments");
+        assert_eq!((error.start, error.end), (10, 11));
+
+        let error = compile_pattern("add(_) )").err().unwrap();
+        assert_eq!(error.message, "Unexpected token after the end of pattern");
+    }
+
+    fn validate(query: &str) -> Result<(), Box<Diagnostic>> {
+        let tokens = Tokenizer::tokenize(query).ok().unwrap();
+        validate_patterns(query, &tokens)
+    }
+
+    fn error_span(query: &str) -> (String, u32, u32, u32) {
+        let diagnostic = validate(query).err().unwrap();
+        let location = diagnostic.location().unwrap();
+        (
+            diagnostic.message().to_string(),
+            location.line_start,
+            location.column_start,
+            location.column_end,
+        )
+    }
+
+    #[test]
+    fn test_validate_patterns() {
+        assert!(
+            validate("SELECT * FROM instructions WHERE m_inst(instruction, m_pattern('_'))")
+                .is_ok()
+        );
+        assert!(validate("SELECT 'm_pattern(foo)' AS m_pattern").is_ok());
+
+        let query = r#"SELECT m_pattern("add(foo, 0)")"#;
+        assert_eq!(
+            error_span(query),
+            (
+                "Invalid pattern, Unknown matcher `foo`".to_string(),
+                1,
+                23,
+                26
+            )
+        );
+
+        // Escaped quotes take two characters in the query
+        let query = r"SELECT m_pattern('call(\'free\') & foo')";
+        assert_eq!(error_span(query).2, 36);
+
+        let query = "SELECT 1\nWHERE m_pattern('foo')";
+        let (_, line, column_start, column_end) = error_span(query);
+        assert_eq!((line, column_start, column_end), (2, 18, 21));
+
+        let diagnostic = validate("SELECT m_pattern('add(_, #q)')").err().unwrap();
+        assert_eq!(
+            diagnostic.helps(),
+            &vec!["Constant classes are #c, #int, #fp, #num, #null, #expr and #poison".to_string()]
+        );
+
+        let diagnostic = validate("SELECT m_pattern(name)").err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "`m_pattern` expects a text literal pattern"
+        );
+    }
+}
@@ -0,0 +1,430 @@
+use std::collections::HashMap;
+
+use gitql_ast::types::boolean::BoolType;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::optional::OptionType;
+use gitql_ast::types::text::TextType;
+use gitql_ast::types::varargs::VarargsType;
+use gitql_ast::types::DataType;
+use gitql_core::signature::Signature;
+use gitql_core::values::boolean::BoolValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+use inkwell::llvm_sys::prelude::LLVMValueRef;
+
+use crate::functions::llvm_ir_functions;
+use crate::ir::types::InstMatcherType;
+use crate::ir::types::TypeMatcherType;
+use crate::ir::values::InstMatcherValue;
+use crate::matchers::combine::CombineBinaryInstMatcher;
+use crate::matchers::combine::CombineUnaryInstMatcher;
+use crate::matchers::constants::ConstExprMatcher;
+use crate::matchers::constants::ConstFloatMatcher;
+use crate::matchers::constants::ConstIntMatcher;
+use crate::matchers::constants::ConstNumberMatcher;
+use crate::matchers::constants::ConstPointerNullMatcher;
+use crate::matchers::other::AnyInstMatcher;
+use crate::matchers::other::PoisonValueMatcher;
+use crate::matchers::Matcher;
+
+use super::tokenizer::PatternToken;
+use super::tokenizer::PatternTokenKind;
+use super::PatternError;
+
+#[derive(Clone, Copy, PartialEq)]
+enum ArgumentKind {
+    InstMatcher,
+    TypeMatcher,
+    Int,
+    Text,
+    Bool,
+}
+
+struct Parameter {
+    kind: Option<ArgumentKind>,
+    is_optional: bool,
+    is_varargs: bool,
+}
+
+/// Recursive descent parser that build matchers using the registered matchers functions,
+/// so `add(_, 0)` is the same as calling `m_add(m_any_inst(), m_specific_int(0))`
+pub struct PatternParser<'a> {
+    tokens: &'a [PatternToken],
+    position: usize,
+    source_len: usize,
+    signatures: &'a HashMap<&'static str, Signature>,
+}
+
+impl<'a> PatternParser<'a> {
+    pub fn new(
+        tokens: &'a [PatternToken],
+        source_len: usize,
+        signatures: &'a HashMap<&'static str, Signature>,
+    ) -> Self {
+        PatternParser {
+            tokens,
+            position: 0,
+            source_len,
+            signatures,
+        }
+    }
+
+    pub fn parse(&mut self) -> Result<Box<dyn Matcher<LLVMValueRef>>, PatternError> {
+        if self.tokens.is_empty() {
+            return Err(PatternError::new(
+                "Pattern can't be empty",
+                0,
+                self.source_len,
+            ));
+        }
+
+        let matcher = self.parse_or_pattern()?;
+        if let Some(token) = self.peek() {
+            return Err(PatternError::new(
+                "Unexpected token after the end of pattern",
+                token.start,
+                token.end,
+            ));
+        }
+
+        Ok(matcher)
+    }
+
+    fn parse_or_pattern(&mut self) -> Result<Box<dyn Matcher<LLVMValueRef>>, PatternError> {
+        let mut lhs = self.parse_xor_pattern()?;
+        while self.consume_if(&PatternTokenKind::Or) {
+            let rhs = self.parse_xor_pattern()?;
+            lhs = Box::new(CombineBinaryInstMatcher::create_or(lhs, rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_xor_pattern(&mut self) -> Result<Box<dyn Matcher<LLVMValueRef>>, PatternError> {
+        let mut lhs = self.parse_and_pattern()?;
+        while self.consume_if(&PatternTokenKind::Xor) {
+            let rhs = self.parse_and_pattern()?;
+            lhs = Box::new(CombineBinaryInstMatcher::create_xor(lhs, rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and_pattern(&mut self) -> Result<Box<dyn Matcher<LLVMValueRef>>, PatternError> {
+        let mut lhs = self.parse_unary_pattern()?;
+        while self.consume_if(&PatternTokenKind::And) {
+            let rhs = self.parse_unary_pattern()?;
+            lhs = Box::new(CombineBinaryInstMatcher::create_and(lhs, rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary_pattern(&mut self) -> Result<Box<dyn Matcher<LLVMValueRef>>, PatternError> {
+        if self.consume_if(&PatternTokenKind::Bang) {
+            let rhs = self.parse_unary_pattern()?;
+            return Ok(Box::new(CombineUnaryInstMatcher::create_not(rhs)));
+        }
+        self.parse_primary_pattern()
+    }
+
+    fn parse_primary_pattern(&mut self) -> Result<Box<dyn Matcher<LLVMValueRef>>, PatternError> {
+        let Some(token) = self.advance() else {
+            return Err(self.error_at_end("Expect pattern but reach end of input"));
+        };
+
+        match &token.kind {
+            PatternTokenKind::LeftParen => {
+                let matcher = self.parse_or_pattern()?;
+                self.expect(&PatternTokenKind::RightParen, "Expect `)` to close group")?;
+                Ok(matcher)
+            }
+            PatternTokenKind::Identifier(name) if name == "_" => Ok(Box::new(AnyInstMatcher)),
+            PatternTokenKind::Identifier(name) => {
+                let value = self.parse_call(name, &token, ArgumentKind::InstMatcher)?;
+                Ok(value
+                    .as_any()
+                    .downcast_ref::<InstMatcherValue>()
+                    .unwrap()
+                    .matcher
+                    .clone())
+            }
+            PatternTokenKind::Integer(value) => {
+                Ok(Box::new(ConstIntMatcher::create_specific_int(*value)))
+            }
+            PatternTokenKind::Constant(class) => {
+                let matcher: Box<dyn Matcher<LLVMValueRef>> =
+                    match class.as_str() {
+                        "c" | "int" => Box::new(ConstIntMatcher::create_const_int()),
+                        "fp" => Box::new(ConstFloatMatcher),
+                        "num" => Box::new(ConstNumberMatcher),
+                        "null" => Box::new(ConstPointerNullMatcher),
+                        "expr" => Box::new(ConstExprMatcher),
+                        "poison" => Box::new(PoisonValueMatcher),
+                        _ => return Err(PatternError::new(
+                            &format!("Unknown constant class `#{class}`"),
+                            token.start,
+                            token.end,
+                        )
+                        .with_help(
+                            "Constant classes are #c, #int, #fp, #num, #null, #expr and #poison",
+                        )),
+                    };
+                Ok(matcher)
+            }
+            _ => Err(PatternError::new("Expect pattern", token.start, token.end)),
+        }
+    }
+
+    /// Parse call like `add(_, 1)` or `unreachable` and evaluate it using the matcher function `m_<name>`
+    fn parse_call(
+        &mut self,
+        name: &str,
+        name_token: &PatternToken,
+        expected_kind: ArgumentKind,
+    ) -> Result<Box<dyn Value>, PatternError> {
+        let function_name = if name.starts_with("m_") {
+            name.to_string()
+        } else {
+            format!("m_{name}")
+        };
+
+        let Some(signature) = self.signatures.get(function_name.as_str()) else {
+            return Err(PatternError::new(
+                &format!("Unknown matcher `{name}`"),
+                name_token.start,
+                name_token.end,
+            ));
+        };
+
+        let return_kind = argument_kind(&signature.return_type);
+        if return_kind != Some(expected_kind) {
+            let expected = if expected_kind == ArgumentKind::TypeMatcher {
+                "a type matcher"
+            } else {
+                "an instruction matcher"
+            };
+            return Err(PatternError::new(
+                &format!("`{name}` is not {expected}"),
+                name_token.start,
+                name_token.end,
+            ));
+        }
+
+        let parameters: Vec<Parameter> = signature.parameters.iter().map(parameter).collect();
+
+        let mut arguments: Vec<Box<dyn Value>> = vec![];
+        let mut call_end = name_token.end;
+        if self.consume_if(&PatternTokenKind::LeftParen) {
+            if !self.consume_if(&PatternTokenKind::RightParen) {
+                loop {
+                    let index = arguments.len();
+                    let parameter = match parameters.get(index) {
+                        Some(parameter) => parameter,
+                        None => match parameters.last() {
+                            Some(last) if last.is_varargs => last,
+                            _ => {
+                                let (start, end) = self.current_span();
+                                return Err(PatternError::new(
+                                    &format!(
+                                        "`{name}` expects at most {} arguments",
+                                        parameters.len()
+                                    ),
+                                    start,
+                                    end,
+                                ));
+                            }
+                        },
+                    };
+
+                    let Some(kind) = parameter.kind else {
+                        return Err(PatternError::new(
+                            &format!("`{name}` can't be used inside pattern"),
+                            name_token.start,
+                            name_token.end,
+                        ));
+                    };
+
+                    arguments.push(self.parse_argument(kind)?);
+
+                    if self.consume_if(&PatternTokenKind::Comma) {
+                        continue;
+                    }
+
+                    self.expect(
+                        &PatternTokenKind::RightParen,
+                        "Expect `,` or `)` after argument",
+                    )?;
+                    break;
+                }
+            }
+            call_end = self.tokens[self.position - 1].end;
+        }
+
+        let required_count = parameters
+            .iter()
+            .filter(|parameter| !parameter.is_optional && !parameter.is_varargs)
+            .count();
+
+        if arguments.len() < required_count {
+            return Err(PatternError::new(
+                &format!("`{name}` expects at least {required_count} arguments"),
+                name_token.start,
+                call_end,
+            ));
+        }
+
+        let function = llvm_ir_functions().get(function_name.as_str()).unwrap();
+        Ok(function(&arguments))
+    }
+
+    fn parse_argument(&mut self, kind: ArgumentKind) -> Result<Box<dyn Value>, PatternError> {
+        match kind {
+            ArgumentKind::InstMatcher => {
+                let matcher = self.parse_or_pattern()?;
+                Ok(Box::new(InstMatcherValue { matcher }))
+            }
+            ArgumentKind::TypeMatcher => match self.advance() {
+                Some(token) => match &token.kind {
+                    PatternTokenKind::Identifier(name) => {
+                        self.parse_call(name, &token, ArgumentKind::TypeMatcher)
+                    }
+                    _ => Err(PatternError::new(
+                        "Expect type matcher like `int32` or `ptr`",
+                        token.start,
+                        token.end,
+                    )),
+                },
+                None => Err(self.error_at_end("Expect type matcher but reach end of input")),
+            },
+            ArgumentKind::Int => match self.advance() {
+                Some(PatternToken {
+                    kind: PatternTokenKind::Integer(value),
+                    ..
+                }) => Ok(Box::new(IntValue { value })),
+                Some(token) => Err(PatternError::new(
+                    "Expect integer argument",
+                    token.start,
+                    token.end,
+                )),
+                None => Err(self.error_at_end("Expect integer but reach end of input")),
+            },
+            ArgumentKind::Text => match self.advance() {
+                Some(PatternToken {
+                    kind: PatternTokenKind::String(value),
+                    ..
+                }) => Ok(Box::new(TextValue::new(value))),
+                Some(token) => Err(PatternError::new(
+                    "Expect string argument",
+                    token.start,
+                    token.end,
+                )),
+                None => Err(self.error_at_end("Expect string but reach end of input")),
+            },
+            ArgumentKind::Bool => match self.advance() {
+                Some(token) => match &token.kind {
+                    PatternTokenKind::Identifier(name) if name == "true" || name == "false" => {
+                        Ok(Box::new(BoolValue {
+                            value: name == "true",
+                        }))
+                    }
+                    _ => Err(PatternError::new(
+                        "Expect `true` or `false`",
+                        token.start,
+                        token.end,
+                    )),
+                },
+                None => Err(self.error_at_end("Expect boolean but reach end of input")),
+            },
+        }
+    }
+
+    fn peek(&self) -> Option<&PatternToken> {
+        self.tokens.get(self.position)
+    }
+
+    fn advance(&mut self) -> Option<PatternToken> {
+        let token = self.tokens.get(self.position).cloned();
+        if token.is_some() {
+            self.position += 1;
+        }
+        token
+    }
+
+    fn consume_if(&mut self, kind: &PatternTokenKind) -> bool {
+        if self.peek().is_some_and(|token| &token.kind == kind) {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, kind: &PatternTokenKind, message: &str) -> Result<(), PatternError> {
+        if self.consume_if(kind) {
+            return Ok(());
+        }
+
+        let (start, end) = self.current_span();
+        Err(PatternError::new(message, start, end))
+    }
+
+    fn current_span(&self) -> (usize, usize) {
+        match self.peek() {
+            Some(token) => (token.start, token.end),
+            None => (self.source_len, self.source_len),
+        }
+    }
+
+    fn error_at_end(&self, message: &str) -> PatternError {
+        PatternError::new(message, self.source_len, self.source_len)
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+fn parameter(data_type: &Box<dyn DataType>) -> Parameter {
+    if let Some(option) = data_type.as_any().downcast_ref::<OptionType>() {
+        return Parameter {
+            kind: option.base.as_ref().and_then(argument_kind),
+            is_optional: true,
+            is_varargs: false,
+        };
+    }
+
+    if let Some(varargs) = data_type.as_any().downcast_ref::<VarargsType>() {
+        return Parameter {
+            kind: argument_kind(&varargs.base),
+            is_optional: false,
+            is_varargs: true,
+        };
+    }
+
+    Parameter {
+        kind: argument_kind(data_type),
+        is_optional: false,
+        is_varargs: false,
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+fn argument_kind(data_type: &Box<dyn DataType>) -> Option<ArgumentKind> {
+    let data_type = data_type.as_any();
+    if data_type.downcast_ref::<InstMatcherType>().is_some() {
+        return Some(ArgumentKind::InstMatcher);
+    }
+
+    if data_type.downcast_ref::<TypeMatcherType>().is_some() {
+        return Some(ArgumentKind::TypeMatcher);
+    }
+
+    if data_type.downcast_ref::<IntType>().is_some() {
+        return Some(ArgumentKind::Int);
+    }
+
+    if data_type.downcast_ref::<TextType>().is_some() {
+        return Some(ArgumentKind::Text);
+    }
+
+    if data_type.downcast_ref::<BoolType>().is_some() {
+        return Some(ArgumentKind::Bool);
+    }
+
+    None
+}
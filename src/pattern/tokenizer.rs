@@ -0,0 +1,158 @@
+use super::PatternError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatternTokenKind {
+    Identifier(String),
+    Constant(String),
+    Integer(i64),
+    String(String),
+    LeftParen,
+    RightParen,
+    Comma,
+    Or,
+    And,
+    Xor,
+    Bang,
+}
+
+/// Pattern token with the span of it in the pattern source
+#[derive(Clone, Debug, PartialEq)]
+pub struct PatternToken {
+    pub kind: PatternTokenKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+pub fn tokenize_pattern(source: &str) -> Result<Vec<PatternToken>, PatternError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens: Vec<PatternToken> = vec![];
+    let mut position = 0;
+
+    while position < chars.len() {
+        let start = position;
+        let current = chars[position];
+
+        if current.is_whitespace() {
+            position += 1;
+            continue;
+        }
+
+        // Identifier or wildcard `_`
+        if current.is_alphabetic() || current == '_' {
+            while position < chars.len() && is_identifier_part(chars[position]) {
+                position += 1;
+            }
+
+            let name: String = chars[start..position].iter().collect();
+            tokens.push(PatternToken {
+                kind: PatternTokenKind::Identifier(name),
+                start,
+                end: position,
+            });
+            continue;
+        }
+
+        // Constant class like `#c` or `#fp`
+        if current == '#' {
+            position += 1;
+            while position < chars.len() && is_identifier_part(chars[position]) {
+                position += 1;
+            }
+
+            if position == start + 1 {
+                return Err(PatternError::new(
+                    "Expect constant class name after `#`",
+                    start,
+                    position,
+                ));
+            }
+
+            let name: String = chars[start + 1..position].iter().collect();
+            tokens.push(PatternToken {
+                kind: PatternTokenKind::Constant(name),
+                start,
+                end: position,
+            });
+            continue;
+        }
+
+        // Integer with optional minus sign
+        if current.is_ascii_digit()
+            || (current == '-'
+                && position + 1 < chars.len()
+                && chars[position + 1].is_ascii_digit())
+        {
+            position += 1;
+            while position < chars.len() && chars[position].is_ascii_digit() {
+                position += 1;
+            }
+
+            let literal: String = chars[start..position].iter().collect();
+            let value = literal
+                .parse::<i64>()
+                .map_err(|_| PatternError::new("Integer literal is too big", start, position))?;
+
+            tokens.push(PatternToken {
+                kind: PatternTokenKind::Integer(value),
+                start,
+                end: position,
+            });
+            continue;
+        }
+
+        // String literal with single or double quotes
+        if current == '\'' || current == '"' {
+            position += 1;
+            while position < chars.len() && chars[position] != current {
+                position += 1;
+            }
+
+            if position >= chars.len() {
+                return Err(PatternError::new(
+                    "Unterminated string literal",
+                    start,
+                    position,
+                ));
+            }
+
+            let literal: String = chars[start + 1..position].iter().collect();
+            position += 1;
+            tokens.push(PatternToken {
+                kind: PatternTokenKind::String(literal),
+                start,
+                end: position,
+            });
+            continue;
+        }
+
+        let kind = match current {
+            '(' => PatternTokenKind::LeftParen,
+            ')' => PatternTokenKind::RightParen,
+            ',' => PatternTokenKind::Comma,
+            '|' => PatternTokenKind::Or,
+            '&' => PatternTokenKind::And,
+            '^' => PatternTokenKind::Xor,
+            '!' => PatternTokenKind::Bang,
+            _ => {
+                return Err(PatternError::new(
+                    &format!("Unexpected character `{current}`"),
+                    start,
+                    start + 1,
+                ))
+            }
+        };
+
+        position += 1;
+        tokens.push(PatternToken {
+            kind,
+            start,
+            end: position,
+        });
+    }
+
+    Ok(tokens)
+}
+
+fn is_identifier_part(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
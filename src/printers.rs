@@ -0,0 +1,181 @@
+use gitql_cli::printer::BaseOutputPrinter;
+use gitql_core::object::GitQLObject;
+
+/// Values longer than this are printed in collapsible cells in the HTML report
+const HTML_COLLAPSE_LENGTH: usize = 80;
+
+const HTML_STYLE: &str = r#"
+body { font-family: sans-serif; margin: 16px; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #d0d7de; padding: 4px 8px; text-align: left; vertical-align: top; }
+th { background: #f6f8fa; cursor: pointer; user-select: none; }
+th[data-order="asc"]::after { content: " \25B2"; }
+th[data-order="desc"]::after { content: " \25BC"; }
+pre { margin: 4px 0 0 0; white-space: pre-wrap; }
+summary { cursor: pointer; }
+"#;
+
+// Sort the rows by the clicked column, numbers are compared as numbers and other values as text
+const HTML_SCRIPT: &str = r#"
+document.querySelectorAll("th").forEach((header, column) => {
+  header.addEventListener("click", () => {
+    const table = header.closest("table");
+    const body = table.tBodies[0];
+    const order = header.dataset.order === "asc" ? "desc" : "asc";
+    table.querySelectorAll("th").forEach(h => delete h.dataset.order);
+    header.dataset.order = order;
+    const value = row => row.cells[column].dataset.value;
+    const rows = Array.from(body.rows).sort((a, b) => {
+      const x = value(a), y = value(b);
+      const result = (x !== "" && y !== "" && !isNaN(x) && !isNaN(y))
+        ? Number(x) - Number(y)
+        : x.localeCompare(y);
+      return order === "asc" ? result : -result;
+    });
+    rows.forEach(row => body.appendChild(row));
+  });
+});
+"#;
+
+/// Print the rows as Markdown table that can be pasted in review comments and wiki pages
+pub struct MarkdownPrinter;
+
+impl BaseOutputPrinter for MarkdownPrinter {
+    fn print(&self, object: &mut GitQLObject) {
+        let titles: Vec<String> = object.titles.iter().map(|t| markdown_cell(t)).collect();
+        println!("| {} |", titles.join(" | "));
+
+        let separators: Vec<&str> = titles.iter().map(|_| "---").collect();
+        println!("| {} |", separators.join(" | "));
+
+        for group in &object.groups {
+            for row in &group.rows {
+                let cells: Vec<String> = row
+                    .values
+                    .iter()
+                    .map(|value| markdown_cell(&value.literal()))
+                    .collect();
+                println!("| {} |", cells.join(" | "));
+            }
+        }
+    }
+}
+
+/// Escape pipes and keep multi-line values inside one table row
+fn markdown_cell(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace('\n', "<br>")
+}
+
+/// Print the rows as self-contained HTML report with sortable columns, and long values
+/// such as instructions are printed in collapsible cells
+pub struct HTMLPrinter;
+
+impl BaseOutputPrinter for HTMLPrinter {
+    fn print(&self, object: &mut GitQLObject) {
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str("<title>LLQL Result</title>\n");
+        html.push_str(&format!("<style>{HTML_STYLE}</style>\n"));
+        html.push_str("</head>\n<body>\n<table>\n<thead>\n<tr>");
+
+        for title in &object.titles {
+            html.push_str(&format!("<th>{}</th>", html_escape(title)));
+        }
+        html.push_str("</tr>\n</thead>\n<tbody>\n");
+
+        for group in &object.groups {
+            for row in &group.rows {
+                html.push_str("<tr>");
+                for value in &row.values {
+                    html.push_str(&html_cell(&value.literal()));
+                }
+                html.push_str("</tr>\n");
+            }
+        }
+
+        html.push_str("</tbody>\n</table>\n");
+        html.push_str(&format!("<script>{HTML_SCRIPT}</script>\n"));
+        html.push_str("</body>\n</html>");
+        println!("{html}");
+    }
+}
+
+/// Create table cell with the full value used for sorting, long or multi-line values
+/// are collapsed to their first line
+fn html_cell(text: &str) -> String {
+    let escaped = html_escape(text);
+    if text.len() <= HTML_COLLAPSE_LENGTH && !text.contains('\n') {
+        return format!("<td data-value=\"{escaped}\">{escaped}</td>");
+    }
+
+    let first_line = text.lines().next().unwrap_or_default();
+    let summary: String = first_line.chars().take(HTML_COLLAPSE_LENGTH).collect();
+    format!(
+        "<td data-value=\"{escaped}\"><details><summary>{}...</summary><pre>{escaped}</pre></details></td>",
+        html_escape(&summary)
+    )
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_cell() {
+        assert_eq!(markdown_cell("add_mul"), "add_mul");
+        assert_eq!(markdown_cell("a | b"), "a \\| b");
+        assert_eq!(markdown_cell("a\\|b"), "a\\\\\\|b");
+        assert_eq!(markdown_cell("line1\nline2"), "line1<br>line2");
+    }
+
+    #[test]
+    fn test_html_escape() {
+        assert_eq!(html_escape("add_mul"), "add_mul");
+        assert_eq!(
+            html_escape(r#"<a href="x">'&'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+        assert_eq!(html_escape("&lt;"), "&amp;lt;");
+    }
+
+    #[test]
+    fn test_html_cell() {
+        assert_eq!(
+            html_cell("i32 <4 x i8>"),
+            "<td data-value=\"i32 &lt;4 x i8&gt;\">i32 &lt;4 x i8&gt;</td>"
+        );
+
+        // Values up to the collapse length are printed as they are
+        let short = "a".repeat(HTML_COLLAPSE_LENGTH);
+        assert_eq!(
+            html_cell(&short),
+            format!("<td data-value=\"{short}\">{short}</td>")
+        );
+
+        let long = "a".repeat(HTML_COLLAPSE_LENGTH + 1);
+        assert_eq!(
+            html_cell(&long),
+            format!(
+                "<td data-value=\"{long}\"><details><summary>{short}...</summary><pre>{long}</pre></details></td>"
+            )
+        );
+    }
+
+    #[test]
+    fn test_html_cell_multi_line() {
+        assert_eq!(
+            html_cell("entry:\n  ret i32 0"),
+            "<td data-value=\"entry:\n  ret i32 0\"><details><summary>entry:...</summary><pre>entry:\n  ret i32 0</pre></details></td>"
+        );
+    }
+}
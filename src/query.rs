@@ -0,0 +1,133 @@
+use std::fmt;
+use std::sync::Arc;
+
+use gitql_core::environment::Environment;
+use gitql_core::values::Value;
+use gitql_engine::data_provider::DataProvider;
+use gitql_engine::engine::EvaluationResult::SelectedGroups;
+use gitql_parser::diagnostic::Diagnostic;
+use gitql_parser::parser;
+use gitql_parser::tokenizer::Tokenizer;
+
+use crate::ir::data_provider::LLVMIRDataProvider;
+use crate::ir::filters::evaluate_with_filters;
+use crate::ir::module_store::ModuleStore;
+use crate::ir::parallel::default_jobs;
+use crate::ir::schema::create_llql_environment;
+use crate::pattern::validate_patterns;
+
+/// Rows selected by a query with the names of the columns
+pub struct QueryResult {
+    pub column_names: Vec<String>,
+    pub rows: Vec<Vec<Box<dyn Value>>>,
+}
+
+impl QueryResult {
+    /// Return the index of the column with this name
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.column_names.iter().position(|column| column == name)
+    }
+}
+
+/// Failure of loading the modules or running the query
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryError {
+    /// Modules can't be read or parsed
+    Load(String),
+    /// Query can't be tokenized, parsed or type checked
+    Syntax(String),
+    /// Query failed while evaluated on the modules
+    Runtime(String),
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryError::Load(message) => write!(f, "Load error: {message}"),
+            QueryError::Syntax(message) => write!(f, "Syntax error: {message}"),
+            QueryError::Runtime(message) => write!(f, "Runtime error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+impl From<Box<Diagnostic>> for QueryError {
+    fn from(diagnostic: Box<Diagnostic>) -> Self {
+        QueryError::Syntax(diagnostic.message().to_string())
+    }
+}
+
+/// Loaded modules with the environment to run one or more queries on them
+pub struct LLQLSession {
+    env: Environment,
+    provider: LLVMIRDataProvider,
+}
+
+impl LLQLSession {
+    /// Load LLVM IR or Bitcode modules from the paths
+    pub fn load(paths: &[String]) -> Result<Self, QueryError> {
+        let store = ModuleStore::load(paths).map_err(QueryError::Load)?;
+        Ok(Self::with_store(store.into_shared()))
+    }
+
+    /// Create a session on modules that are already loaded
+    pub fn with_store(store: Arc<ModuleStore>) -> Self {
+        Self::with_jobs(store, default_jobs())
+    }
+
+    /// Create a session that select rows from up to `jobs` modules in parallel
+    pub fn with_jobs(store: Arc<ModuleStore>, jobs: usize) -> Self {
+        LLQLSession {
+            env: create_llql_environment(),
+            provider: LLVMIRDataProvider::with_jobs(store, jobs),
+        }
+    }
+
+    /// Run the query and return the result of each select statement
+    pub fn query(&mut self, query: &str) -> Result<Vec<QueryResult>, QueryError> {
+        let result = self.evaluate(query);
+        self.env.clear_session();
+        result
+    }
+
+    fn evaluate(&mut self, query: &str) -> Result<Vec<QueryResult>, QueryError> {
+        let query = query.to_string();
+        let tokens = Tokenizer::tokenize(&query)?;
+        if tokens.is_empty() {
+            return Ok(vec![]);
+        }
+
+        validate_patterns(&query, &tokens)?;
+        let query_node = parser::parse_gql(tokens, &mut self.env)?;
+        let filters = self.provider.filters();
+        let provider: Box<dyn DataProvider> = Box::new(self.provider.clone());
+        let evaluations_results =
+            evaluate_with_filters(&mut self.env, &provider, &filters, query_node)
+                .map_err(QueryError::Runtime)?;
+
+        let mut results: Vec<QueryResult> = vec![];
+        for evaluation_result in evaluations_results {
+            if let SelectedGroups(object) = evaluation_result {
+                let rows = object
+                    .groups
+                    .into_iter()
+                    .flat_map(|group| group.rows)
+                    .map(|row| row.values)
+                    .collect();
+
+                results.push(QueryResult {
+                    column_names: object.titles,
+                    rows,
+                });
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+/// Load the modules and run the query on them
+pub fn run_query(paths: &[String], query: &str) -> Result<Vec<QueryResult>, QueryError> {
+    LLQLSession::load(paths)?.query(query)
+}
@@ -0,0 +1,228 @@
+use std::sync::Arc;
+
+use gitql_core::environment::Environment;
+use llql::functions::llvm_ir_function_signatures;
+use llql::inputs::resolve_input_files;
+use llql::inputs::validate_files_paths;
+use llql::ir::module_store::LoadOptions;
+use llql::ir::module_store::ModuleStore;
+
+use crate::history::History;
+
+const REPL_COMMANDS_HELP: [(&str, &str); 9] = [
+    (
+        ".load <paths>",
+        "Load one or more LLVM IR or Bitcode files or directories",
+    ),
+    (
+        ".unload [paths]",
+        "Unload files, or all files if no path is passed",
+    ),
+    (".reload", "Reload all loaded files from the disk"),
+    (".files", "List the loaded files"),
+    (".tables", "List the available tables"),
+    (
+        ".schema <table>",
+        "List the columns of the table with their types",
+    ),
+    (
+        ".functions",
+        "List matchers functions with their signatures",
+    ),
+    (
+        ".history [text]",
+        "Search the line editor history from the most recent query",
+    ),
+    (".help", "Print this help"),
+];
+
+/// Return true if the REPL input is a meta command not a query
+pub fn is_repl_command(input: &str) -> bool {
+    input.trim_start().starts_with('.')
+}
+
+/// Execute REPL meta command that may change the loaded files
+pub fn execute_repl_command(
+    input: &str,
+    files: &mut Vec<String>,
+    store: &mut Arc<ModuleStore>,
+    env: &Environment,
+    options: &LoadOptions,
+    history: Option<&History>,
+) -> Result<(), String> {
+    let mut parts = input.split_whitespace();
+    let command = parts.next().unwrap_or_default();
+    let arguments: Vec<String> = parts.map(|part| part.to_string()).collect();
+
+    match command {
+        ".load" => load_files(files, store, options, arguments),
+        ".unload" => unload_files(files, store, options, arguments),
+        ".reload" => {
+            *store = ModuleStore::load_with_options(files, options)?.into_shared();
+            println!("Reloaded {} files", files.len());
+            Ok(())
+        }
+        ".files" => {
+            for file in files.iter() {
+                println!("{file}");
+            }
+            Ok(())
+        }
+        ".tables" => {
+            let mut tables: Vec<&&str> = env.schema.tables_fields_names.keys().collect();
+            tables.sort();
+            for table in tables {
+                println!("{table}");
+            }
+            Ok(())
+        }
+        ".schema" => print_table_schema(env, arguments.first()),
+        ".functions" => {
+            let signatures = llvm_ir_function_signatures();
+            let mut names: Vec<&&str> = signatures
+                .keys()
+                .filter(|name| name.starts_with("m_") || name.starts_with("f_"))
+                .collect();
+            names.sort();
+            for name in names {
+                let signature = &signatures[*name];
+                let parameters: Vec<String> = signature
+                    .parameters
+                    .iter()
+                    .map(|parameter| parameter.literal())
+                    .collect();
+                let return_type = signature.return_type.literal();
+                println!("{name}({}) -> {return_type}", parameters.join(", "));
+            }
+            Ok(())
+        }
+        ".history" => {
+            let Some(history) = history else {
+                return Err("Command `.history` is available only in the line editor".to_string());
+            };
+
+            let pattern = arguments.join(" ");
+            for entry in history.search_reverse(&pattern) {
+                println!("{entry}");
+            }
+            Ok(())
+        }
+        ".help" => {
+            for (command, description) in REPL_COMMANDS_HELP {
+                println!("{command:<18} {description}");
+            }
+            Ok(())
+        }
+        _ => Err(format!(
+            "Unknown command `{command}`, use `.help` to list commands"
+        )),
+    }
+}
+
+fn load_files(
+    files: &mut Vec<String>,
+    store: &mut Arc<ModuleStore>,
+    options: &LoadOptions,
+    paths: Vec<String>,
+) -> Result<(), String> {
+    if paths.is_empty() {
+        return Err("Command `.load` must be followed by one or more path".to_string());
+    }
+
+    let paths = resolve_input_files(&paths, &[], &[])?;
+    validate_files_paths(&paths)?;
+
+    let mut new_files = files.clone();
+    for path in paths {
+        if !new_files.contains(&path) {
+            new_files.push(path);
+        }
+    }
+
+    // Keep the current files and modules if any of the new files can't be parsed
+    *store = ModuleStore::load_with_options(&new_files, options)?.into_shared();
+    *files = new_files;
+
+    println!("Loaded {} files", files.len());
+    Ok(())
+}
+
+fn unload_files(
+    files: &mut Vec<String>,
+    store: &mut Arc<ModuleStore>,
+    options: &LoadOptions,
+    paths: Vec<String>,
+) -> Result<(), String> {
+    if paths.is_empty() {
+        files.clear();
+    } else {
+        if let Some(path) = paths.iter().find(|path| !files.contains(path)) {
+            return Err(format!("File {path} is not loaded"));
+        }
+        files.retain(|file| !paths.contains(file));
+    }
+
+    *store = ModuleStore::load_with_options(files, options)?.into_shared();
+    println!("Loaded {} files", files.len());
+    Ok(())
+}
+
+fn print_table_schema(env: &Environment, table: Option<&String>) -> Result<(), String> {
+    let Some(table) = table else {
+        return Err("Command `.schema` must be followed by table name".to_string());
+    };
+
+    let Some(columns) = env.schema.tables_fields_names.get(table.as_str()) else {
+        return Err(format!("Unknown table `{table}`"));
+    };
+
+    for column in columns {
+        let column_type = env.schema.tables_fields_types[column].literal();
+        println!("{column:<20} {column_type}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_path(name: &str) -> String {
+        format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+    }
+
+    #[test]
+    fn test_load_and_unload_files() {
+        let options = LoadOptions::default();
+        let mut store = ModuleStore::default().into_shared();
+        let mut files: Vec<String> = vec![];
+        let sample = fixture_path("sample.ll");
+        let math = fixture_path("math.ll");
+
+        load_files(&mut files, &mut store, &options, vec![sample.clone()]).unwrap();
+        load_files(
+            &mut files,
+            &mut store,
+            &options,
+            vec![math.clone(), sample.clone()],
+        )
+        .unwrap();
+        assert_eq!(files, [sample.clone(), math.clone()]);
+
+        // Loading missing file keeps the current files
+        let missing = fixture_path("missing.ll");
+        assert!(load_files(&mut files, &mut store, &options, vec![missing.clone()]).is_err());
+        assert!(load_files(&mut files, &mut store, &options, vec![]).is_err());
+        assert_eq!(files, [sample.clone(), math.clone()]);
+
+        let error = unload_files(&mut files, &mut store, &options, vec![missing.clone()]);
+        assert_eq!(error, Err(format!("File {missing} is not loaded")));
+        assert_eq!(files.len(), 2);
+
+        unload_files(&mut files, &mut store, &options, vec![sample]).unwrap();
+        assert_eq!(files, [math]);
+
+        unload_files(&mut files, &mut store, &options, vec![]).unwrap();
+        assert!(files.is_empty());
+    }
+}
@@ -0,0 +1,405 @@
+use std::ffi::c_char;
+use std::ffi::CStr;
+use std::path::Path;
+
+use gitql_core::values::Value;
+use inkwell::llvm_sys::core::LLVMGetBasicBlockParent;
+use inkwell::llvm_sys::core::LLVMGetDebugLocColumn;
+use inkwell::llvm_sys::core::LLVMGetDebugLocDirectory;
+use inkwell::llvm_sys::core::LLVMGetDebugLocFilename;
+use inkwell::llvm_sys::core::LLVMGetDebugLocLine;
+use inkwell::llvm_sys::prelude::LLVMValueRef;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::check::Finding;
+use crate::check::Rule;
+use crate::check::Severity;
+use crate::ir::values::LLVMBasicBlockValue;
+use crate::ir::values::LLVMFunctionValue;
+use crate::ir::values::LLVMInstValue;
+use crate::matchers::matchers_helper::instruction_parent_block;
+use crate::matchers::matchers_helper::llvm_basic_block_name;
+use crate::matchers::matchers_helper::llvm_value_name;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Source location of the instruction from its debug location
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SourceLocation {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// Location of the finding, the source location is used when the row has an instruction
+/// with debug location, otherwise the function and block names are used
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FindingLocation {
+    pub source: Option<SourceLocation>,
+    pub function_name: Option<String>,
+    pub basic_block_name: Option<String>,
+}
+
+impl FindingLocation {
+    /// Find the location from the first instruction, block or function value in the row,
+    /// or from the `function_name` and `basic_block_name` columns
+    pub fn from_finding(finding: &Finding) -> Self {
+        let mut location = FindingLocation::default();
+
+        for value in &finding.values {
+            let value = value.as_any();
+            if let Some(instruction) = value.downcast_ref::<LLVMInstValue>() {
+                location.source = instruction_source_location(instruction.llvm_value);
+                if let Some(basic_block) = instruction_parent_block(&instruction.llvm_value) {
+                    let function = unsafe { LLVMGetBasicBlockParent(basic_block) };
+                    location.function_name = Some(llvm_value_name(&function));
+                    location.basic_block_name = Some(llvm_basic_block_name(&basic_block));
+                }
+                return location;
+            }
+
+            if let Some(basic_block) = value.downcast_ref::<LLVMBasicBlockValue>() {
+                let function = unsafe { LLVMGetBasicBlockParent(basic_block.basic_block) };
+                location.function_name = Some(llvm_value_name(&function));
+                location.basic_block_name = Some(llvm_basic_block_name(&basic_block.basic_block));
+                return location;
+            }
+
+            if let Some(function) = value.downcast_ref::<LLVMFunctionValue>() {
+                location.function_name = Some(llvm_value_name(&function.llvm_function));
+                return location;
+            }
+        }
+
+        let text_column = |name: &str| finding.column(name).and_then(|value| value.as_text());
+        location.function_name = text_column("function_name");
+        location.basic_block_name = text_column("basic_block_name");
+        location
+    }
+
+    /// Return the location name as `@function:%block` without the module name
+    pub fn qualified_name(&self) -> Option<String> {
+        let function_name = self.function_name.as_ref()?;
+        match &self.basic_block_name {
+            Some(basic_block_name) => Some(format!("@{function_name}:%{basic_block_name}")),
+            None => Some(format!("@{function_name}")),
+        }
+    }
+}
+
+fn instruction_source_location(instruction: LLVMValueRef) -> Option<SourceLocation> {
+    unsafe {
+        // Line zero means that instruction has no debug location
+        let line = LLVMGetDebugLocLine(instruction);
+        if line == 0 {
+            return None;
+        }
+
+        let mut length: u32 = 0;
+        let file_name = c_string(LLVMGetDebugLocFilename(instruction, &mut length));
+        let directory = c_string(LLVMGetDebugLocDirectory(instruction, &mut length));
+
+        let file = if directory.is_empty() || Path::new(&file_name).is_absolute() {
+            file_name
+        } else {
+            Path::new(&directory)
+                .join(file_name)
+                .to_string_lossy()
+                .to_string()
+        };
+
+        Some(SourceLocation {
+            file,
+            line,
+            column: LLVMGetDebugLocColumn(instruction),
+        })
+    }
+}
+
+fn c_string(pointer: *const c_char) -> String {
+    if pointer.is_null() {
+        return String::new();
+    }
+    unsafe { CStr::from_ptr(pointer).to_string_lossy().to_string() }
+}
+
+/// Create SARIF 2.1 report with one result for each finding
+pub fn sarif_report(rules: &[Rule], findings: &[Finding]) -> JsonValue {
+    let rules: Vec<JsonValue> = rules
+        .iter()
+        .map(|rule| {
+            json!({
+                "id": rule.name,
+                "shortDescription": { "text": rule.message },
+                "defaultConfiguration": { "level": sarif_level(rule.severity) },
+            })
+        })
+        .collect();
+
+    let results: Vec<JsonValue> = findings.iter().map(sarif_result).collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "LLQL",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/AmrDeveloper/LLQL",
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
+fn sarif_result(finding: &Finding) -> JsonValue {
+    let location = FindingLocation::from_finding(finding);
+    let mut sarif_location = json!({});
+
+    if let Some(source) = &location.source {
+        let mut region = json!({ "startLine": source.line });
+        // Column zero means that the column is unknown
+        if source.column > 0 {
+            region["startColumn"] = json!(source.column);
+        }
+
+        sarif_location["physicalLocation"] = json!({
+            "artifactLocation": { "uri": source.file },
+            "region": region,
+        });
+    }
+
+    if let (Some(function_name), Some(qualified_name)) =
+        (&location.function_name, location.qualified_name())
+    {
+        sarif_location["logicalLocations"] = json!([{
+            "name": function_name,
+            "fullyQualifiedName": qualified_name,
+            "kind": "function",
+        }]);
+    }
+
+    let locations = if sarif_location.as_object().is_some_and(|o| o.is_empty()) {
+        vec![]
+    } else {
+        vec![sarif_location]
+    };
+
+    json!({
+        "ruleId": finding.rule,
+        "level": sarif_level(finding.severity),
+        "message": { "text": finding.message },
+        "locations": locations,
+    })
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Note => "note",
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+/// Create the rule of the findings that are selected by a query not by a rules file
+pub fn query_rule(query: &str) -> Rule {
+    Rule {
+        name: "llql-query".to_string(),
+        severity: Severity::Note,
+        message: "Row selected by LLQL query".to_string(),
+        query: query.to_string(),
+    }
+}
+
+/// Create finding from each row of the query result
+pub fn query_findings(
+    rule: &Rule,
+    column_names: &[String],
+    rows: Vec<Vec<Box<dyn Value>>>,
+) -> Vec<Finding> {
+    rows.into_iter()
+        .map(|values| {
+            let columns: Vec<String> = column_names
+                .iter()
+                .zip(values.iter())
+                .map(|(column, value)| format!("{column}: {}", value.literal()))
+                .collect();
+
+            Finding {
+                rule: rule.name.to_string(),
+                severity: rule.severity,
+                message: columns.join(", "),
+                column_names: column_names.to_vec(),
+                values,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use gitql_core::values::text::TextValue;
+
+    use super::*;
+    use crate::query::LLQLSession;
+    use crate::test_utils;
+
+    const SOURCE_FILE: &str = "/home/user/project/src/debug.c";
+
+    /// Run the query on the debug fixture module and return a finding for each row
+    fn debug_findings(rule: &Rule, query: &str) -> Vec<Finding> {
+        let mut session = LLQLSession::load(&[test_utils::fixture_path("debug.ll")]).unwrap();
+        let mut results = session.query(query).unwrap();
+        let result = results.remove(0);
+        query_findings(rule, &result.column_names, result.rows)
+    }
+
+    fn source_location(file: &str, line: u32, column: u32) -> Option<SourceLocation> {
+        Some(SourceLocation {
+            file: file.to_string(),
+            line,
+            column,
+        })
+    }
+
+    #[test]
+    fn test_location_from_debug_location() {
+        let query = "SELECT instruction FROM instructions";
+        let locations: Vec<FindingLocation> = debug_findings(&query_rule(query), query)
+            .iter()
+            .map(FindingLocation::from_finding)
+            .collect();
+
+        let sources: Vec<Option<SourceLocation>> =
+            locations.iter().map(|l| l.source.clone()).collect();
+        assert_eq!(
+            sources,
+            vec![
+                source_location(SOURCE_FILE, 4, 13),
+                source_location(SOURCE_FILE, 5, 0),
+                None,
+                source_location(SOURCE_FILE, 6, 3),
+                source_location("/usr/include/header.h", 11, 10),
+                source_location("/usr/include/header.h", 11, 10),
+            ]
+        );
+
+        // Instructions without debug location still have the function and block names
+        assert_eq!(locations[2].function_name.as_deref(), Some("compute"));
+        assert_eq!(locations[2].basic_block_name.as_deref(), Some("entry"));
+        assert_eq!(
+            locations[4].qualified_name().as_deref(),
+            Some("@header:%entry")
+        );
+    }
+
+    #[test]
+    fn test_location_fallbacks() {
+        let location = |query: &str| {
+            let findings = debug_findings(&query_rule(query), query);
+            FindingLocation::from_finding(&findings[0])
+        };
+
+        let block = location("SELECT parent_block(instruction) FROM instructions LIMIT 1");
+        assert_eq!(block.source, None);
+        assert_eq!(block.qualified_name().as_deref(), Some("@compute:%entry"));
+
+        let function = location("SELECT function FROM functions WHERE function_name = 'header'");
+        assert_eq!(function.source, None);
+        assert_eq!(function.qualified_name().as_deref(), Some("@header"));
+
+        let columns = location("SELECT basic_block_name, function_name FROM instructions LIMIT 1");
+        assert_eq!(columns.source, None);
+        assert_eq!(columns.qualified_name().as_deref(), Some("@compute:%entry"));
+
+        let finding = Finding {
+            rule: "llql-query".to_string(),
+            severity: Severity::Note,
+            message: "name: value".to_string(),
+            column_names: vec!["name".to_string()],
+            values: vec![Box::new(TextValue::new("value".to_string()))],
+        };
+        assert_eq!(
+            FindingLocation::from_finding(&finding),
+            FindingLocation::default()
+        );
+        assert_eq!(FindingLocation::default().qualified_name(), None);
+    }
+
+    #[test]
+    fn test_sarif_report() {
+        let rule = |name: &str, severity: Severity| Rule {
+            name: name.to_string(),
+            severity,
+            message: format!("{name} message"),
+            query: String::new(),
+        };
+        let rules = vec![
+            rule("note-rule", Severity::Note),
+            rule("warning-rule", Severity::Warning),
+            rule("error-rule", Severity::Error),
+        ];
+
+        let query = "SELECT instruction FROM instructions WHERE function_name = 'compute' LIMIT 2";
+        let mut findings = debug_findings(&rules[2], query);
+        findings.push(Finding {
+            rule: "warning-rule".to_string(),
+            severity: Severity::Warning,
+            message: "Without location".to_string(),
+            column_names: vec![],
+            values: vec![],
+        });
+
+        let report = sarif_report(&rules, &findings);
+        assert_eq!(report["version"], "2.1.0");
+        assert_eq!(report["$schema"], SARIF_SCHEMA);
+
+        let run = &report["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "LLQL");
+        let levels: Vec<&str> = run["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|rule| rule["defaultConfiguration"]["level"].as_str().unwrap())
+            .collect();
+        assert_eq!(levels, vec!["note", "warning", "error"]);
+        assert_eq!(run["tool"]["driver"]["rules"][1]["id"], "warning-rule");
+        assert_eq!(
+            run["tool"]["driver"]["rules"][1]["shortDescription"]["text"],
+            "warning-rule message"
+        );
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0],
+            json!({
+                "ruleId": "error-rule",
+                "level": "error",
+                "message": { "text": "instruction:   %sum = add i32 %x, 1, !dbg !7" },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": SOURCE_FILE },
+                        "region": { "startLine": 4, "startColumn": 13 },
+                    },
+                    "logicalLocations": [{
+                        "name": "compute",
+                        "fullyQualifiedName": "@compute:%entry",
+                        "kind": "function",
+                    }],
+                }],
+            })
+        );
+
+        // Column zero is not reported
+        let region = &results[1]["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region, &json!({ "startLine": 5 }));
+
+        assert_eq!(results[2]["level"], "warning");
+        assert_eq!(results[2]["locations"], json!([]));
+    }
+}
@@ -0,0 +1,38 @@
+//! Helpers shared by the tests that run queries on the modules in `tests/fixtures`
+
+use crate::query::LLQLSession;
+
+/// Return the path of the fixture module with this file name
+pub fn fixture_path(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Run the query on the fixture module and return the literals of each selected row
+pub fn query_rows(fixture: &str, query: &str) -> Vec<Vec<String>> {
+    let mut session = LLQLSession::load(&[fixture_path(fixture)]).unwrap();
+    let results = session.query(query).unwrap();
+    results[0]
+        .rows
+        .iter()
+        .map(|row| row.iter().map(|value| value.literal()).collect())
+        .collect()
+}
+
+/// Run the query on the fixture module and return the literals of the first column
+pub fn query_column(fixture: &str, query: &str) -> Vec<String> {
+    query_rows(fixture, query)
+        .into_iter()
+        .map(|mut row| row.swap_remove(0))
+        .collect()
+}
+
+/// Return `function:block` of each instruction in the fixture module matched by the matcher
+pub fn matched_blocks(fixture: &str, matcher: &str) -> Vec<String> {
+    let query = format!(
+        "SELECT function_name, basic_block_name FROM instructions WHERE m_inst(instruction, {matcher})"
+    );
+    query_rows(fixture, &query)
+        .iter()
+        .map(|row| row.join(":"))
+        .collect()
+}
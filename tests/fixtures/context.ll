@@ -0,0 +1,28 @@
+; Module used by the tests of the context matchers
+source_filename = "context.cpp"
+
+declare void @free(ptr)
+declare void @abort()
+
+define void @_Z7cleanupPv(ptr %p) #0 {
+entry:
+  call void @free(ptr %p)
+  %null = icmp eq ptr %p, null
+  br i1 %null, label %fail, label %done
+
+fail:
+  call void @abort()
+  unreachable
+
+done:
+  call void @free(ptr %p)
+  ret void
+}
+
+define void @release(ptr %p) {
+entry:
+  call void @free(ptr %p)
+  ret void
+}
+
+attributes #0 = { nounwind }
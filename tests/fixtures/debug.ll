@@ -0,0 +1,32 @@
+; Module with debug locations used by the tests of the SARIF report locations
+source_filename = "debug.c"
+
+define i32 @compute(i32 %x) !dbg !6 {
+entry:
+  %sum = add i32 %x, 1, !dbg !7
+  %product = mul i32 %sum, 2, !dbg !8
+  %result = sub i32 %product, 3
+  ret i32 %result, !dbg !9
+}
+
+define i32 @header(i32 %x) !dbg !10 {
+entry:
+  %double = shl i32 %x, 1, !dbg !11
+  ret i32 %double, !dbg !11
+}
+
+!llvm.dbg.cu = !{!0}
+!llvm.module.flags = !{!3, !4}
+
+!0 = distinct !DICompileUnit(language: DW_LANG_C99, file: !1, producer: "clang", isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug)
+!1 = !DIFile(filename: "src/debug.c", directory: "/home/user/project")
+!2 = !DIFile(filename: "/usr/include/header.h", directory: "/home/user/project")
+!3 = !{i32 2, !"Debug Info Version", i32 3}
+!4 = !{i32 7, !"Dwarf Version", i32 5}
+!5 = !DISubroutineType(types: !{})
+!6 = distinct !DISubprogram(name: "compute", scope: !1, file: !1, line: 3, type: !5, scopeLine: 3, spFlags: DISPFlagDefinition, unit: !0)
+!7 = !DILocation(line: 4, column: 13, scope: !6)
+!8 = !DILocation(line: 5, scope: !6)
+!9 = !DILocation(line: 6, column: 3, scope: !6)
+!10 = distinct !DISubprogram(name: "header", scope: !2, file: !2, line: 10, type: !5, scopeLine: 10, spFlags: DISPFlagDefinition, unit: !0)
+!11 = !DILocation(line: 11, column: 10, scope: !10)
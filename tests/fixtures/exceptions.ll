@@ -0,0 +1,78 @@
+; Exception handling module with Windows funclets and Itanium landing pads
+source_filename = "exceptions.cpp"
+
+@_ZTIi = external constant ptr
+
+declare i32 @__CxxFrameHandler3(...)
+declare i32 @__gxx_personality_v0(...)
+declare void @may_throw()
+declare void @cleanup()
+
+define void @funclets() personality ptr @__CxxFrameHandler3 {
+entry:
+  invoke void @may_throw()
+          to label %exit unwind label %dispatch
+
+dispatch:
+  %cs = catchswitch within none [label %handler] unwind to caller
+
+handler:
+  %cp = catchpad within %cs [ptr null, i32 64, ptr null]
+  invoke void @may_throw() [ "funclet"(token %cp) ]
+          to label %handler_exit unwind label %inner_dispatch
+
+handler_exit:
+  catchret from %cp to label %exit
+
+inner_dispatch:
+  %inner_cs = catchswitch within %cp [label %inner_handler] unwind label %inner_cleanup
+
+inner_handler:
+  %inner_cp = catchpad within %inner_cs [ptr null, i32 64, ptr null]
+  catchret from %inner_cp to label %handler_exit
+
+inner_cleanup:
+  %inner_cl = cleanuppad within %cp [i32 7]
+  call void @cleanup() [ "funclet"(token %inner_cl) ]
+  cleanupret from %inner_cl unwind to caller
+
+exit:
+  ret void
+}
+
+define void @top_cleanup() personality ptr @__CxxFrameHandler3 {
+entry:
+  invoke void @may_throw()
+          to label %exit unwind label %cleanup_block
+
+cleanup_block:
+  %cl = cleanuppad within none []
+  call void @cleanup() [ "funclet"(token %cl) ]
+  cleanupret from %cl unwind to caller
+
+exit:
+  ret void
+}
+
+define void @landing_pads() personality ptr @__gxx_personality_v0 {
+entry:
+  invoke void @may_throw()
+          to label %next unwind label %catch_lpad
+
+next:
+  invoke void @may_throw()
+          to label %exit unwind label %cleanup_lpad
+
+catch_lpad:
+  %catch = landingpad { ptr, i32 }
+          catch ptr @_ZTIi
+  resume { ptr, i32 } %catch
+
+cleanup_lpad:
+  %cleanup = landingpad { ptr, i32 }
+          cleanup
+  resume { ptr, i32 } %cleanup
+
+exit:
+  ret void
+}
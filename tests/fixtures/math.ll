@@ -0,0 +1,8 @@
+; Second module used by the tests that load more than one module
+source_filename = "math.c"
+
+define i32 @square(i32 %x) {
+entry:
+  %result = mul i32 %x, %x
+  ret i32 %result
+}
@@ -0,0 +1,37 @@
+; Small module used by the tests of the matchers, functions and tables
+source_filename = "sample.c"
+
+@counter = global i32 0
+
+define i32 @add_mul(i32 %a, i32 %b) {
+entry:
+  %mul = mul i32 %a, 3
+  %add = add i32 %mul, 0
+  ret i32 %add
+}
+
+define internal i32 @div(i32 %a, i32 %b) #0 {
+entry:
+  %div = sdiv i32 %a, %b
+  ret i32 %div
+}
+
+declare ptr @malloc(i64)
+
+define i32 @loop(i32 %n) {
+entry:
+  %buffer = call ptr @malloc(i64 8)
+  br label %loop
+
+loop:
+  %i = phi i32 [ 0, %entry ], [ %next, %loop ]
+  %next = add i32 %i, 1
+  %done = icmp eq i32 %next, %n
+  br i1 %done, label %exit, label %loop
+
+exit:
+  %value = load i32, ptr @counter
+  ret i32 %value
+}
+
+attributes #0 = { noinline nounwind }